@@ -1,4 +1,6 @@
 fn main() {
+  generate_api();
+
   let git_output = std::process::Command::new("git")
     .args(["rev-parse", "--git-dir"])
     .output()
@@ -53,3 +55,174 @@ fn main() {
 
   println!("cargo:rustc-env=_GIT_INFO={}", git_describe);
 }
+
+/// The IRX API's schema, from which its client is generated.
+const OPENAPI: &str = "src/irx_client/openapi.json";
+
+/// Generates the IRX API's models and endpoints from its schema.
+///
+/// Models come from typify. Each operation becomes a struct holding its
+/// path parameters and request body, implementing `api::Endpoint`. The
+/// hand-written `ApiKey` stands in for the schema's, and string properties
+/// holding JSON (`contentSchema`) are decoded through `api::Enveloped`.
+fn generate_api() {
+  use serde_json::{json, Map, Value};
+
+  println!("cargo:rerun-if-changed={OPENAPI}");
+  let spec: Value = serde_json::from_str(
+    &std::fs::read_to_string(OPENAPI).expect("schema is readable"),
+  )
+  .expect("schema is valid JSON");
+
+  let mut settings = typify::TypeSpaceSettings::default();
+  settings.with_replacement(
+    "ApiKey",
+    "ApiKey",
+    [
+      typify::TypeSpaceImpl::FromStr,
+      typify::TypeSpaceImpl::Display,
+    ]
+    .into_iter(),
+  );
+  let mut definitions = spec["components"]["schemas"]
+    .as_object()
+    .expect("schema has components")
+    .clone();
+  let mut enveloped = Map::new();
+  for schema in definitions.values_mut() {
+    let Some(properties) =
+      schema.get_mut("properties").and_then(Value::as_object_mut)
+    else {
+      continue;
+    };
+    for property in properties.values_mut() {
+      let Some(inner) = property.pointer("/contentSchema/$ref") else {
+        continue;
+      };
+      let inner = inner.as_str().unwrap().rsplit('/').next().unwrap();
+      let name = format!("Enveloped{inner}");
+      settings.with_replacement(
+        &name,
+        format!("Enveloped<{inner}>"),
+        std::iter::empty(),
+      );
+      enveloped.insert(name.clone(), json!({ "type": "string" }));
+      let reference = format!("#/components/schemas/{name}");
+      *property = json!({ "$ref": reference });
+    }
+  }
+  definitions.extend(enveloped);
+  let definitions = serde_json::to_string(&definitions)
+    .unwrap()
+    .replace("#/components/schemas/", "#/definitions/");
+  let root =
+    serde_json::from_str(&format!(r#"{{"definitions":{definitions}}}"#))
+      .expect("schemas are JSON schemas");
+  let mut types = typify::TypeSpace::new(&settings);
+  types.add_root_schema(root).expect("schemas are supported");
+
+  let mut out = types.to_stream().to_string();
+  let parameters = &spec["components"]["parameters"];
+  for (path, operations) in spec["paths"].as_object().expect("schema has paths")
+  {
+    for (method, operation) in operations.as_object().unwrap() {
+      out.push_str(&endpoint(path, method, operation, parameters));
+    }
+  }
+  let file = syn::parse_file(&out).expect("generated code parses");
+  let out_dir = std::env::var("OUT_DIR").unwrap();
+  std::fs::write(
+    std::path::Path::new(&out_dir).join("irx_api.rs"),
+    prettyplease::unparse(&file),
+  )
+  .expect("generated code is written");
+}
+
+/// Generates the struct and `Endpoint` impl for one operation.
+fn endpoint(
+  path: &str,
+  method: &str,
+  operation: &serde_json::Value,
+  parameters: &serde_json::Value,
+) -> String {
+  let id = operation["operationId"]
+    .as_str()
+    .expect("operation has an id");
+  let name: String = id
+    .split('_')
+    .flat_map(|word| {
+      let mut chars = word.chars();
+      chars
+        .next()
+        .map(|c| c.to_ascii_uppercase())
+        .into_iter()
+        .chain(chars)
+    })
+    .collect();
+  let schema_type = |schema: &serde_json::Value| {
+    schema["$ref"]
+      .as_str()
+      .map(|r| r.rsplit('/').next().unwrap().to_string())
+  };
+
+  let mut fields = Vec::new();
+  let mut args = Vec::new();
+  for parameter in operation["parameters"].as_array().into_iter().flatten() {
+    let parameter = match parameter["$ref"].as_str() {
+      Some(r) => &parameters[r.rsplit('/').next().unwrap()],
+      None => parameter,
+    };
+    assert_eq!(
+      parameter["in"], "path",
+      "only path parameters are supported"
+    );
+    let name = parameter["name"].as_str().unwrap();
+    fields.push(format!("pub {name}: String,"));
+    args.push(format!("{name} = self.{name}"));
+  }
+  let body = &operation["requestBody"];
+  let (body_type, body_fn) =
+    match body["content"]["application/json"]["schema"].as_object() {
+      Some(schema) => {
+        let ty = schema_type(&serde_json::Value::from(schema.clone()))
+          .expect("request bodies are components");
+        if body["required"] == true {
+          fields.push(format!("pub body: {ty},"));
+          (ty, "Some(&self.body)")
+        } else {
+          fields.push(format!("pub body: Option<{ty}>,"));
+          (ty, "self.body.as_ref()")
+        }
+      }
+      None => (String::from("()"), "None"),
+    };
+  let response = schema_type(
+    &operation["responses"]["200"]["content"]["application/json"]["schema"],
+  )
+  .unwrap_or_else(|| String::from("NoContent"));
+  let summary = operation["summary"].as_str().unwrap_or_default();
+  let method = method.to_ascii_uppercase();
+  let path = path.trim_start_matches('/');
+  let fields = match fields.is_empty() {
+    true => String::from(";"),
+    false => format!("{{ {} }}", fields.join(" ")),
+  };
+  let path = match args.is_empty() {
+    true => format!("String::from({path:?})"),
+    false => format!("format!({path:?}, {})", args.join(", ")),
+  };
+  format!(
+    r#"
+    #[doc = {summary:?}]
+    #[derive(Clone, Debug)]
+    pub struct {name} {fields}
+    impl Endpoint for {name} {{
+      const METHOD: reqwest::Method = reqwest::Method::{method};
+      type Body = {body_type};
+      type Response = {response};
+      fn path(&self) -> String {{ {path} }}
+      fn body(&self) -> Option<&Self::Body> {{ {body_fn} }}
+    }}
+    "#
+  )
+}
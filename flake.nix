@@ -14,7 +14,7 @@
         inherit (pkgs) lib;
 
         rustPackageSet = pkgs.rustBuilder.makePackageSet {
-          rustVersion = "1.71.1";
+          rustVersion = "1.85.0";
           packageFun = import ./Cargo.nix;
           extraRustComponents = [ "rustfmt" "clippy" ];
         };
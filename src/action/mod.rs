@@ -33,6 +33,14 @@ pub enum Action {
   ToggleOverlay(Overlay),
   ChangeMode(Mode),
   Help,
+  Solve,
+  SolveRemote,
+  Cancel,
+  RegenerateKey,
+  RevokeKey,
+  RevealKey,
+  EditParams,
+  EditEnsemble,
 }
 
 impl<'de> Deserialize<'de> for Action {
@@ -78,6 +86,14 @@ impl<'de> Deserialize<'de> for Action {
             Ok(Action::ToggleOverlay(Overlay::UsageInfo))
           }
           "Help" => Ok(Action::Help),
+          "Solve" => Ok(Action::Solve),
+          "SolveRemote" => Ok(Action::SolveRemote),
+          "Cancel" => Ok(Action::Cancel),
+          "RegenerateKey" => Ok(Action::RegenerateKey),
+          "RevokeKey" => Ok(Action::RevokeKey),
+          "RevealKey" => Ok(Action::RevealKey),
+          "EditParams" => Ok(Action::EditParams),
+          "EditEnsemble" => Ok(Action::EditEnsemble),
           data if data.starts_with("Error(") => {
             let error_msg =
               data.trim_start_matches("Error(").trim_end_matches(')');
@@ -2,18 +2,24 @@ use crate::{
   action::mode::Mode,
   action::scene::Scene,
   action::Action,
+  cli::Cli,
   components::{
     base::Base, home::Home, internals::Internals, session::Session,
     usage_info::UsageInfo, Component,
   },
   config::Config,
-  irx_client::IrxClient,
-  router::{Address, Message, Router},
+  irx_client::{
+    Http, HttpRegistrar, IrxClient, Settings, KEY_ENV, PASSPHRASE_ENV,
+    REGISTRATION_KEY_ENV,
+  },
+  job::LocalRunner,
+  router::{Address, Message, Payload, Router},
   tui,
 };
 use color_eyre::eyre::Result;
 use crossterm::event::KeyEvent;
 use ratatui::prelude::Rect;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 
 /// The main application structure for Napali's `App`.
@@ -44,20 +50,27 @@ pub struct App {
   router: Router,
   /// Channel for sending messages to the application itself.
   pub message_tx_to_self: mpsc::UnboundedSender<Message>,
+  /// Receiver for messages from the Router, such as dead-letter notices.
+  message_rx_from_router: mpsc::UnboundedReceiver<Message>,
   /// Client for interacting with the Irx API.
   pub client: IrxClient,
+  /// Runner for solver jobs executed on this machine.
+  pub local_runner: LocalRunner,
 }
 
 impl App {
   /// Constructs a new instance of `App`.
   ///
-  /// Initializes the application with specified tick and frame rates, sets up
-  /// components, message channels, and default states.
+  /// Initializes the application with the tick and frame rates given on the
+  /// command line, sets up components, message channels, and default states.
   ///
   /// # Parameters
   ///
-  /// * `tick_rate`: The rate at which the app's logic updates.
-  /// * `frame_rate`: The rate at which the app renders frames.
+  /// * `args`: The parsed command-line arguments.
+  ///   - `tick_rate`: The rate at which the app's logic updates.
+  ///   - `frame_rate`: The rate at which the app renders frames.
+  ///   - `problem`: An optional MPS file to open in the Session scene.
+  ///   - `endpoint`, `key_file`, `offline`: Overrides for the IRX client settings.
   ///
   /// # Returns
   ///
@@ -65,30 +78,61 @@ impl App {
   ///
   /// # Errors
   ///
-  /// Returns an error if initialization fails (e.g., network issues, configuration errors).
+  /// Returns an error if initialization fails (e.g., configuration errors).
   ///
   /// # Examples
   ///
   /// ```
   /// #[tokio::main]
   /// async fn main() {
-  ///     let app = App::new(60.0, 30.0).await.expect("Failed to create App");
+  ///     let app = App::new(&Cli::parse()).await.expect("Failed to create App");
   ///     // Use `app` here
   /// }
   /// ```
-  pub async fn new(tick_rate: f64, frame_rate: f64) -> Result<Self> {
-    let (message_tx_to_self, _) = mpsc::unbounded_channel::<Message>();
+  pub async fn new(args: &Cli) -> Result<Self> {
+    let (message_tx_to_self, message_rx_from_router) =
+      mpsc::unbounded_channel::<Message>();
     let (mut router, message_tx_to_router) =
       Router::new(message_tx_to_self.clone()).await?;
     let base = Base::new();
-    let internals = Internals::new(message_tx_to_router.clone());
+    let internals = Internals::new(router.handle());
     let home = Home::new(message_tx_to_router.clone());
     let usage_info = UsageInfo::default();
     let config = Config::new()?;
     let scene = Scene::Internals;
     let mode = Mode::Navigation;
-    let client = IrxClient::new(message_tx_to_router.clone()).await?;
-    let session = Session::new();
+    let mut settings = Settings::resolve(
+      args.endpoint.clone(),
+      args.key_file.clone(),
+      args.profile.clone(),
+      std::env::var(KEY_ENV).ok(),
+      std::env::var(PASSPHRASE_ENV).ok(),
+      args.offline,
+      &config.config.irx,
+    )?;
+    // Without a usable registration key, only registering is unavailable
+    let registration_key = HttpRegistrar::resolve_key(
+      std::env::var(REGISTRATION_KEY_ENV).ok(),
+      config.config.irx.registration_key.as_deref(),
+    )
+    .unwrap_or_else(|e| {
+      settings.key_warnings.push(e.to_string());
+      None
+    });
+    let http = Http::configure(&settings)?;
+    let registrar = HttpRegistrar::new(
+      http.clone(),
+      settings.endpoint.clone(),
+      registration_key,
+    );
+    let client = IrxClient::new(
+      message_tx_to_router.clone(),
+      settings,
+      http,
+      Arc::new(registrar),
+    )?;
+    let local_runner = LocalRunner::new(message_tx_to_router.clone());
+    let session = Session::new(router.handle());
     router.register(Address::Home, home.message_tx_to_self.clone());
     router.register(Address::Internals, internals.message_tx_to_self.clone());
     router.register(
@@ -97,10 +141,18 @@ impl App {
     );
     router.register(Address::IrxClient, client.message_tx_to_self.clone());
     router.register(Address::Session, session.message_tx_to_self.clone());
+    // Opened once registered, so that the loaded problem reaches the session
+    if let Some(path) = &args.problem {
+      session.open(path);
+    }
+    router.register(
+      Address::LocalRunner,
+      local_runner.message_tx_to_self.clone(),
+    );
 
     Ok(Self {
-      tick_rate,
-      frame_rate,
+      tick_rate: args.tick_rate,
+      frame_rate: args.frame_rate,
       components: vec![
         Box::new(internals),
         Box::new(home),
@@ -117,7 +169,9 @@ impl App {
       last_tick_key_events: Vec::new(),
       router,
       message_tx_to_self,
+      message_rx_from_router,
       client,
+      local_runner,
     })
   }
 
@@ -139,7 +193,7 @@ impl App {
   /// ```
   /// #[tokio::main]
   /// async fn main() {
-  ///     let mut app = App::new(60.0, 30.0).await.expect("Failed to create App");
+  ///     let mut app = App::new(&Cli::parse()).await.expect("Failed to create App");
   ///     app.run().await.expect("Failed to run App");
   /// }
   /// ```
@@ -148,6 +202,7 @@ impl App {
     action_tx.send(Action::ChangeScene(Scene::default()))?;
     self.router.run();
     self.client.run_responder();
+    self.local_runner.run();
 
     let mut tui = tui::Tui::new()?
       .tick_rate(self.tick_rate)
@@ -168,6 +223,9 @@ impl App {
           tui::Event::Tick => action_tx.send(Action::Tick)?,
           tui::Event::Render => action_tx.send(Action::Render)?,
           tui::Event::Resize(x, y) => action_tx.send(Action::Resize(x, y))?,
+          // Keys typed into a text field are left to the component
+          // that has the field
+          tui::Event::Key(_) if self.mode == Mode::TextInput => {}
           tui::Event::Key(key) => {
             if let Some(keymap) = self.config.keybindings.get(&self.scene) {
               if let Some(action) = keymap.get(&vec![key]) {
@@ -195,6 +253,12 @@ impl App {
         }
       }
 
+      while let Ok(message) = self.message_rx_from_router.try_recv() {
+        if let Payload::DeadLetter(letter) = message.payload {
+          action_tx.send(Action::Error(letter.to_string()))?;
+        }
+      }
+
       while let Ok(action) = action_rx.try_recv() {
         if action != Action::Tick && action != Action::Render {
           log::debug!("{action:?}");
@@ -220,6 +284,7 @@ impl App {
             })?;
           }
           Action::ChangeScene(scene) => self.scene = scene,
+          Action::ChangeMode(mode) => self.mode = mode,
           Action::Render => {
             tui.draw(|f| {
               for component in &mut self.components {
@@ -261,11 +326,12 @@ impl App {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use clap::Parser;
   use color_eyre::eyre::Result;
 
   #[tokio::test]
   async fn test_app_new() -> Result<()> {
-    let _ = App::new(1.0, 60.0).await?;
+    let _ = App::new(&Cli::parse_from(["napali"])).await?;
     Ok(())
   }
 }
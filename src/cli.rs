@@ -1,5 +1,7 @@
 use crate::utils::version;
 use clap::Parser;
+use std::path::PathBuf;
+use url::Url;
 
 /// Command-line interface (CLI) arguments for Napali.
 ///
@@ -61,4 +63,86 @@ pub struct Cli {
     default_value_t = false
   )]
   pub console_subscriber: bool,
+
+  /// MPS problem file to open in the Session scene.
+  ///
+  /// Both free and fixed format MPS files are accepted. Parse errors are
+  /// reported in the Session's Logs pane.
+  ///
+  /// # Arguments
+  ///
+  /// * `-p`, `--problem`: (Optional) Path to a `.mps` file.
+  #[arg(
+    short,
+    long,
+    value_name = "FILE",
+    help = "MPS problem file to open in the Session"
+  )]
+  pub problem: Option<PathBuf>,
+
+  /// Base URL of the IRX API.
+  ///
+  /// Overrides `irx.endpoint` from the config file.
+  ///
+  /// # Arguments
+  ///
+  /// * `-e`, `--endpoint`: (Optional) The API's base URL.
+  #[arg(
+    short,
+    long,
+    value_name = "URL",
+    help = "Base URL of the IRX API [default: https://api.irx.sh/]"
+  )]
+  pub endpoint: Option<Url>,
+
+  /// File holding the IRX API key.
+  ///
+  /// Overrides `irx.key_file` from the config file. The `IRX_API_KEY`
+  /// environment variable takes precedence over any key file.
+  ///
+  /// # Arguments
+  ///
+  /// * `-k`, `--key-file`: (Optional) Path to the key file.
+  #[arg(
+    short,
+    long,
+    value_name = "FILE",
+    help = "File holding the IRX API key [default: ~/.config/irx/key.txt]"
+  )]
+  pub key_file: Option<PathBuf>,
+
+  /// Profile whose API key is used.
+  ///
+  /// Overrides `irx.profile` from the config file. Each profile keeps its
+  /// own key in the key file, and may set its own endpoint under
+  /// `irx.profiles`.
+  ///
+  /// # Arguments
+  ///
+  /// * `--profile`: (Optional) Name of the profile.
+  #[arg(
+    long,
+    value_name = "NAME",
+    help = "Profile whose API key is used [default: default]"
+  )]
+  pub profile: Option<String>,
+
+  /// Flag to run without contacting the IRX API.
+  ///
+  /// Skips API key provisioning and disables remote features; local solving
+  /// keeps working. Without this flag, Napali still goes offline on its own
+  /// when the API can't be reached.
+  ///
+  /// # Arguments
+  ///
+  /// * `-o`, `--offline`: (Optional) Boolean flag to enable offline mode.
+  /// * `default_value_t = false`: Disabled by default.
+  #[arg(
+    short,
+    long,
+    value_name = "BOOL",
+    help = "Run without contacting the IRX API",
+    default_value_t = false
+  )]
+  pub offline: bool,
 }
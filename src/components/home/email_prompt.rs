@@ -1,5 +1,6 @@
 use crate::action::mode::Mode;
 use crate::action::Action;
+use crate::irx_client::{Connectivity, Registration};
 use crate::router::{Address, Cacheable, Kind, Message, Payload};
 use crate::tui::Frame;
 use color_eyre::eyre::{eyre, Result};
@@ -22,12 +23,16 @@ use tui_textarea::{Input, Key, TextArea};
 /// - `text`: `TextArea` widget for email input.
 /// - `is_active`: Boolean indicating if the prompt is currently active.
 /// - `is_valid`: Boolean indicating if the entered email is valid.
+/// - `connectivity`: Whether the API can be reached; the prompt can only be activated when it can.
+/// - `registration`: How the last submitted address fared, if one was submitted.
 #[derive(Debug)]
 pub struct EmailPrompt<'a> {
   message_tx_to_router: mpsc::UnboundedSender<Message>,
   text: TextArea<'a>,
   is_active: bool,
   is_valid: bool,
+  connectivity: Connectivity,
+  registration: Option<Registration>,
 }
 
 impl<'a> EmailPrompt<'a> {
@@ -46,6 +51,8 @@ impl<'a> EmailPrompt<'a> {
       text: TextArea::default(),
       is_active: false,
       is_valid: false,
+      connectivity: Connectivity::default(),
+      registration: None,
     }
   }
 
@@ -70,7 +77,10 @@ impl<'a> EmailPrompt<'a> {
         vertical: 0,
       }))[0]
     } else {
-      self.text.set_block(Self::inactive_block_style());
+      self.text.set_block(Self::inactive_block_style(
+        self.connectivity,
+        self.registration.as_ref(),
+      ));
       layout.split(area)[0]
     }
   }
@@ -90,23 +100,63 @@ impl<'a> EmailPrompt<'a> {
   }
 
   /// Creates a block style for the inactive state of the prompt.
-  fn inactive_block_style() -> Block<'a> {
+  ///
+  /// The title notes when the API can't be reached, and otherwise how the
+  /// last registration went.
+  fn inactive_block_style(
+    connectivity: Connectivity,
+    registration: Option<&Registration>,
+  ) -> Block<'a> {
+    let (title, color) = match registration {
+      _ if !connectivity.is_online() => {
+        (format!("Email Prompt ({connectivity})"), Color::Reset)
+      }
+      None => (String::from("Email Prompt"), Color::Reset),
+      Some(r @ Registration::Pending) => {
+        (format!("Email Prompt ({r})"), Color::Yellow)
+      }
+      Some(r @ Registration::Registered(_)) => {
+        (format!("Email Prompt ({r})"), Color::LightGreen)
+      }
+      Some(r @ Registration::Failed(_)) => {
+        (format!("Email Prompt ({r})"), Color::LightRed)
+      }
+    };
     Block::default()
-      .title("Email Prompt")
+      .title(title)
       .title_alignment(Alignment::Left)
+      .title_style(Style::default().fg(color))
       .borders(Borders::ALL)
       .style(Style::default())
   }
 
-  /// Activates the email prompt.
+  /// Activates the email prompt, unless the API can't be reached or an
+  /// address is being, or has been, registered this session.
   ///
   /// # Returns
   /// A result indicating success or failure, and optionally an action to be taken.
   pub fn activate(&mut self) -> Result<Option<Action>> {
+    let registering = matches!(
+      self.registration,
+      Some(Registration::Pending | Registration::Registered(_))
+    );
+    if !self.connectivity.is_online() || registering {
+      return Ok(None);
+    }
     self.is_active = true;
     Ok(Some(Action::ChangeMode(Mode::TextInput)))
   }
 
+  /// Sets whether the API can be reached.
+  pub fn set_connectivity(&mut self, connectivity: Connectivity) {
+    self.connectivity = connectivity;
+  }
+
+  /// Sets how the last submitted address fared.
+  pub fn set_registration(&mut self, registration: Registration) {
+    self.registration = Some(registration);
+  }
+
   /// Deactivates the email prompt.
   pub fn deactivate(&mut self) {
     self.is_active = false;
@@ -180,7 +230,7 @@ impl<'a> EmailPrompt<'a> {
         self.deactivate();
         let email = self.get_email()?;
         if self.upgrade_api_key(email).is_ok() {
-          // TODO: indicate success and disable the email prompt for the session
+          self.registration = Some(Registration::Pending);
           self.reset();
         }
       }
@@ -283,10 +333,42 @@ mod tests {
     prompt.deactivate();
   }
 
+  #[test]
+  fn test_activate_offline() -> Result<()> {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let mut prompt = EmailPrompt::new(tx);
+    prompt.set_connectivity(Connectivity::Offline);
+    assert_eq!(prompt.activate()?, None);
+    assert!(!prompt.is_active());
+    Ok(())
+  }
+
+  #[test]
+  fn test_activate_registered() -> Result<()> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+    let mut prompt = EmailPrompt::new(tx);
+    prompt.set_connectivity(Connectivity::Online);
+    prompt.activate()?;
+    for c in "me@example.com".chars() {
+      let event = KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+      prompt.handle_key_event(event)?;
+    }
+    let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::empty());
+    prompt.handle_key_event(enter)?;
+    assert!(matches!(rx.try_recv()?.payload, Payload::Email(_)));
+    assert_eq!(prompt.registration, Some(Registration::Pending));
+    assert_eq!(prompt.activate()?, None);
+
+    prompt.set_registration(Registration::Failed(String::from("timeout")));
+    assert!(prompt.activate()?.is_some());
+    Ok(())
+  }
+
   #[test]
   fn test_is_active() {
     let (tx, _) = mpsc::unbounded_channel::<Message>();
     let mut prompt = EmailPrompt::new(tx);
+    prompt.set_connectivity(Connectivity::Online);
     let _ = prompt.activate();
     assert!(prompt.is_active());
     prompt.deactivate();
@@ -1,6 +1,6 @@
 use super::{Component, State};
 use crate::action::{mode::Mode, scene::Scene, view::View, Action};
-use crate::router::Message;
+use crate::router::{Message, Payload};
 use crate::{tui::Event, tui::Frame};
 use color_eyre::eyre::Result;
 use ratatui::prelude::*;
@@ -15,6 +15,7 @@ use email_prompt::EmailPrompt;
 ///
 /// # Fields
 /// - `state`: Current state of the Home component.
+/// - `message_rx_from_router`: Receiver for messages from the router.
 /// - `message_tx_to_self`: Sender for passing messages to the Home component itself.
 /// - `email_prompt`: Component for handling email prompt functionality.
 /// - `about`: Component representing the about section.
@@ -22,6 +23,7 @@ use email_prompt::EmailPrompt;
 #[derive(Debug)]
 pub struct Home<'a> {
   state: State,
+  message_rx_from_router: mpsc::UnboundedReceiver<Message>,
   pub message_tx_to_self: mpsc::UnboundedSender<Message>,
   email_prompt: EmailPrompt<'a>,
   mode: Mode,
@@ -39,9 +41,11 @@ impl<'a> Home<'a> {
   /// # Returns
   /// A new instance of `Home`.
   pub fn new(tx: mpsc::UnboundedSender<Message>) -> Home<'a> {
-    let (message_tx_to_self, _) = mpsc::unbounded_channel::<Message>();
+    let (message_tx_to_self, message_rx_from_router) =
+      mpsc::unbounded_channel::<Message>();
     Home {
       state: State::Visible,
+      message_rx_from_router,
       message_tx_to_self,
       email_prompt: EmailPrompt::new(tx),
       mode: Mode::default(),
@@ -51,9 +55,28 @@ impl<'a> Home<'a> {
   /// Determines if Napali should return to navigation mode from text input mode.
   ///
   /// # Returns
-  /// `true` if the email prompt is inactive and the current mode is `TextInput`, otherwise `false`.
+  /// `true` if Home is visible, the email prompt is inactive and the current
+  /// mode is `TextInput`, otherwise `false`.
   fn should_restore_navigation_mode(&self) -> bool {
-    !self.email_prompt.is_active() && (self.mode == Mode::TextInput)
+    !self.email_prompt.is_active()
+      && (self.mode == Mode::TextInput)
+      && (self.state == State::Visible)
+  }
+
+  /// Applies connectivity changes and registration results received from
+  /// the router.
+  fn receive(&mut self) {
+    while let Ok(message) = self.message_rx_from_router.try_recv() {
+      match message.payload {
+        Payload::Connectivity(connectivity) => {
+          self.email_prompt.set_connectivity(connectivity);
+        }
+        Payload::Registration(registration) => {
+          self.email_prompt.set_registration(registration);
+        }
+        _ => {}
+      }
+    }
   }
 }
 
@@ -68,9 +91,11 @@ impl<'a> Component for Home<'a> {
   /// # Returns
   /// A result indicating successful processing and optionally a new action to be taken.
   fn update(&mut self, action: Action) -> Result<Option<Action>> {
-    // Handle mode change actions directly
-    if let Action::ChangeMode(mode) = action {
-      self.mode = mode;
+    // Handle mode change actions directly, and pick up messages on each tick
+    match action {
+      Action::ChangeMode(mode) => self.mode = mode,
+      Action::Tick => self.receive(),
+      _ => {}
     }
 
     // Process actions based on the current mode
@@ -38,6 +38,22 @@ pub struct Buffers {
   pub toggle_overlay: ConstGenericRingBuffer<u32, 512>,
   /// Buffer for storing help request events.
   pub help: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing job submission events.
+  pub solve: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing remote job submission events.
+  pub solve_remote: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing job cancellation events.
+  pub cancel: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing key regeneration events.
+  pub regenerate_key: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing key revocation events.
+  pub revoke_key: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing key reveal toggle events.
+  pub reveal_key: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing parameter form events.
+  pub edit_params: ConstGenericRingBuffer<u32, 512>,
+  /// Buffer for storing ensemble form events.
+  pub edit_ensemble: ConstGenericRingBuffer<u32, 512>,
   /// Buffer for storing application trails as strings. Smaller size due to larger data per entry.
   pub trail: ConstGenericRingBuffer<String, 32>,
 }
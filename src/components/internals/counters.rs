@@ -40,4 +40,31 @@ pub struct Counters {
   pub toggle_overlay: u32,
   /// Counter for help request events.
   pub help: u32,
+  /// Counter for job submission events.
+  pub solve: u32,
+  /// Counter for remote job submission events.
+  pub solve_remote: u32,
+  /// Counter for job cancellation events.
+  pub cancel: u32,
+  /// Counter for key regeneration events.
+  pub regenerate_key: u32,
+  /// Counter for key revocation events.
+  pub revoke_key: u32,
+  /// Counter for key reveal toggle events.
+  pub reveal_key: u32,
+  /// Counter for parameter form events.
+  pub edit_params: u32,
+  /// Counter for ensemble form events.
+  pub edit_ensemble: u32,
+  /// Counter for messages routed to an unregistered address.
+  pub unregistered: u32,
+  /// Counter for messages routed to an address whose receiver is gone.
+  pub closed: u32,
+}
+
+impl Counters {
+  /// Returns the total number of messages the router couldn't deliver.
+  pub fn dead_letters(&self) -> u32 {
+    self.unregistered.saturating_add(self.closed)
+  }
 }
@@ -1,6 +1,6 @@
 use super::{Component, State};
 use crate::action::{mode::Mode, scene::Scene, Action};
-use crate::router::Message;
+use crate::router::{Message, RouterHandle};
 use crate::tui::Frame;
 use color_eyre::eyre::Result;
 use ratatui::prelude::*;
@@ -25,7 +25,9 @@ use trail::Trail;
 ///
 /// # Fields
 /// - `state`: Current state of the Internals component.
+/// - `router`: Handle for talking to other components through the router.
 /// - `message_tx_to_self`: Sender for passing messages to the Internals component itself.
+/// - `message_rx_from_router`: Receiver for messages from the router.
 /// - `state_display`: Component for displaying the state.
 /// - `stats_display`: Component for displaying statistics.
 /// - `actions`: Buffer storing actions.
@@ -34,7 +36,9 @@ use trail::Trail;
 #[derive(Debug)]
 pub struct Internals {
   state: State,
+  router: RouterHandle,
   pub message_tx_to_self: mpsc::UnboundedSender<Message>,
+  message_rx_from_router: mpsc::UnboundedReceiver<Message>,
   state_display: StateDisplay,
   stats_display: StatsDisplay,
   actions: Buffers,
@@ -49,16 +53,19 @@ impl Internals {
   /// for communication with the router and within the component.
   ///
   /// # Arguments
-  /// - `tx`: Sender for passing messages to the router.
+  /// - `router`: Handle for talking to other components through the router.
   ///
   /// # Returns
   /// A new instance of `Internals`.
-  pub fn new(tx: mpsc::UnboundedSender<Message>) -> Internals {
-    let (message_tx_to_self, _) = mpsc::unbounded_channel::<Message>();
+  pub fn new(router: RouterHandle) -> Internals {
+    let (message_tx_to_self, message_rx_from_router) =
+      mpsc::unbounded_channel::<Message>();
     Internals {
       state: State::Visible,
+      router: router.clone(),
       message_tx_to_self,
-      state_display: StateDisplay::new(tx),
+      message_rx_from_router,
+      state_display: StateDisplay::new(router),
       stats_display: StatsDisplay::new(),
       actions: Buffers::default(),
       counters: Counters::default(),
@@ -73,6 +80,16 @@ impl Internals {
   pub fn get_state_display_tx_handle(&self) -> mpsc::UnboundedSender<Message> {
     self.state_display.get_tx_handle()
   }
+
+  /// Drains messages from the router and refreshes the dead-letter counters.
+  fn receive(&mut self) {
+    while let Ok(message) = self.message_rx_from_router.try_recv() {
+      log::debug!("Internals ignored {:?}", message.payload);
+    }
+    let dead_letters = self.router.dead_letters();
+    self.counters.unregistered = dead_letters.unregistered;
+    self.counters.closed = dead_letters.closed;
+  }
 }
 
 impl Component for Internals {
@@ -97,17 +114,30 @@ impl Component for Internals {
       Action::Tick => {
         // Process application ticks
         self.stats_display.app_tick(&mut self.counters);
+        self.receive();
       }
       Action::Render => {
         // Process rendering ticks
         self.stats_display.render_tick(&mut self.counters);
       }
+      Action::RegenerateKey if self.state == State::Visible => {
+        self.state_display.regenerate_key();
+      }
+      Action::RevokeKey if self.state == State::Visible => {
+        self.state_display.revoke_key();
+      }
+      Action::RevealKey if self.state == State::Visible => {
+        self.state_display.toggle_reveal();
+      }
       Action::ChangeScene(scene) => match self.mode {
         Mode::Navigation => {
           // Update visibility based on scene changes
           match scene {
             Scene::Internals => self.state = State::Visible,
-            _ => self.state = State::Hidden,
+            _ => {
+              self.state = State::Hidden;
+              self.state_display.conceal();
+            }
           }
         }
         Mode::TextInput => {}
@@ -154,10 +184,13 @@ impl Component for Internals {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::router::Router;
 
-  #[test]
-  fn test_internals_new() {
+  #[tokio::test]
+  async fn test_internals_new() -> Result<()> {
     let (tx, _) = mpsc::unbounded_channel::<Message>();
-    let _ = Internals::new(tx);
+    let (router, _) = Router::new(tx).await?;
+    let _ = Internals::new(router.handle());
+    Ok(())
   }
 }
@@ -1,93 +1,234 @@
-use crate::router::{Address, Cacheable, Kind, Message, Payload};
+use crate::irx_client::{Account, KeyStatus};
+use crate::router::{Address, Cacheable, Kind, Message, Payload, RouterHandle};
 use color_eyre::eyre::{eyre, Result};
+use futures::FutureExt;
 use ratatui::{
   prelude::*,
   widgets::{block::Block, BorderType, Borders, Paragraph},
 };
-use std::collections::HashMap;
-use tokio::sync::mpsc;
+use std::time::{Duration, Instant};
+use tokio::{sync::mpsc, task::JoinHandle};
 
 /// Manages the display of application state information in a TUI environment.
 ///
 /// This struct is responsible for fetching and displaying various pieces of application state,
-/// such as API keys and request counts. It uses asynchronous communication to retrieve data.
+/// such as API keys and request counts. Questions are asked through the router in the
+/// background, so rendering never waits on their answers.
+///
+/// The key is shown masked unless revealed, and is masked again when the scene is left.
+/// It can be regenerated or revoked from here; revoking must be confirmed by asking twice.
 ///
 /// # Fields
-/// - `message_tx_to_router`: Sender for passing messages to the router.
+/// - `router`: Handle for asking other components through the router.
 /// - `message_rx_from_router`: Receiver for messages from the router.
 /// - `message_tx_to_self`: Sender for passing messages to the `StateDisplay` component itself.
-/// - `cache`: A cache for storing fetched state data.
+/// - `key_status`: The latest answer about the client's credentials.
+/// - `key_request`: The question about the credentials still awaiting an answer.
+/// - `account`: The latest answer about the account.
+/// - `account_request`: The question about the account still awaiting an answer.
+/// - `revoke_requested`: When revoking the key was first asked for, awaiting confirmation.
+/// - `reveal`: Whether the key is shown in full.
 #[derive(Debug)]
 pub struct StateDisplay {
-  message_tx_to_router: mpsc::UnboundedSender<Message>,
-  message_rx_from_router: Option<mpsc::UnboundedReceiver<Message>>,
+  router: RouterHandle,
+  _message_rx_from_router: mpsc::UnboundedReceiver<Message>,
   pub message_tx_to_self: mpsc::UnboundedSender<Message>,
-  cache: HashMap<String, String>,
+  key_status: Option<KeyStatus>,
+  key_request: Option<JoinHandle<Result<KeyStatus>>>,
+  account: Option<Account>,
+  account_request: Option<JoinHandle<Result<Account>>>,
+  revoke_requested: Option<Instant>,
+  reveal: bool,
 }
 
 impl StateDisplay {
+  /// How long to wait for the client to answer.
+  const TIMEOUT: Duration = Duration::from_secs(1);
+
+  /// How long a request to revoke the key waits for confirmation.
+  const CONFIRM: Duration = Duration::from_secs(5);
+
   /// Constructs a new instance of `StateDisplay`.
   ///
-  /// Initializes the state display with message channels for communication and an empty cache.
+  /// Initializes the state display with message channels for communication.
   ///
   /// # Arguments
-  /// - `tx`: Sender for passing messages to the router.
+  /// - `router`: Handle for asking other components through the router.
   ///
   /// # Returns
   /// A new instance of `StateDisplay`.
-  pub fn new(tx: mpsc::UnboundedSender<Message>) -> StateDisplay {
+  pub fn new(router: RouterHandle) -> StateDisplay {
     let (message_tx_to_self, message_rx_from_router) =
       mpsc::unbounded_channel::<Message>();
     StateDisplay {
-      message_tx_to_router: tx,
-      message_rx_from_router: Some(message_rx_from_router),
+      router,
+      _message_rx_from_router: message_rx_from_router,
       message_tx_to_self,
-      cache: HashMap::new(),
+      key_status: None,
+      key_request: None,
+      account: None,
+      account_request: None,
+      revoke_requested: None,
+      reveal: false,
     }
   }
 
-  /// Synchronously requests and retrieves the API key using the provided runtime handle.
+  /// Asks the client a question in the background.
+  ///
+  /// # Arguments
+  /// - `question`: The payload to ask with.
+  ///
+  /// # Returns
+  /// A handle resolving to the payload of the answer.
+  fn ask(&self, question: Payload) -> JoinHandle<Result<Payload>> {
+    let router = self.router.clone();
+    tokio::spawn(async move {
+      let reply = router
+        .ask(
+          Address::StateDisplay,
+          Address::IrxClient,
+          question,
+          Self::TIMEOUT,
+        )
+        .await?;
+      Ok(reply.payload)
+    })
+  }
+
+  /// Asks the client for its credentials in the background.
+  ///
+  /// # Returns
+  /// A handle resolving to the API key and where it was found.
+  fn ask_for_key(&self) -> JoinHandle<Result<KeyStatus>> {
+    let answer = self.ask(Payload::Empty);
+    tokio::spawn(async move {
+      match answer.await?? {
+        Payload::KeyStatus(status) => Ok(status),
+        payload => Err(eyre!("invalid payload {payload:?}")),
+      }
+    })
+  }
+
+  /// Asks the client about the account in the background.
+  ///
+  /// # Returns
+  /// A handle resolving to the tier and request counts.
+  fn ask_for_account(&self) -> JoinHandle<Result<Account>> {
+    let answer = self.ask(Payload::Account(Account::default()));
+    tokio::spawn(async move {
+      match answer.await?? {
+        Payload::Account(account) => Ok(account),
+        payload => Err(eyre!("invalid payload {payload:?}")),
+      }
+    })
+  }
+
+  /// Takes the answer to a question, if it has arrived.
   ///
   /// # Arguments
-  /// - `handle`: The Tokio runtime handle used for asynchronous operations.
+  /// - `request`: The question still awaiting an answer, if any.
   ///
   /// # Returns
-  /// The retrieved API key as a `String`, or an error if the operation fails.
-  pub fn ask_for_key_sync(
-    &mut self,
-    handle: tokio::runtime::Handle,
-  ) -> Result<String> {
-    let request = Message {
+  /// The answer, or `None` if there's none yet or it failed.
+  fn settle<T>(request: &mut Option<JoinHandle<Result<T>>>) -> Option<T> {
+    if !request.as_ref().is_some_and(JoinHandle::is_finished) {
+      return None;
+    }
+    match request.take().and_then(FutureExt::now_or_never) {
+      Some(Ok(Ok(answer))) => Some(answer),
+      Some(Ok(Err(e))) => {
+        log::warn!("{e:?}");
+        None
+      }
+      Some(Err(e)) => {
+        log::error!("{e:?}");
+        None
+      }
+      None => None,
+    }
+  }
+
+  /// Returns the latest known credentials, and asks for fresh ones.
+  ///
+  /// # Returns
+  /// The latest status, or `None` while the first answer is outstanding.
+  fn key_status(&mut self) -> Option<KeyStatus> {
+    if let Some(status) = Self::settle(&mut self.key_request) {
+      self.key_status = Some(status);
+    }
+    // Repeated questions are answered from the router's cache
+    if self.key_request.is_none() {
+      self.key_request = Some(self.ask_for_key());
+    }
+    self.key_status.clone()
+  }
+
+  /// Returns the latest known account, and asks for a fresh one.
+  ///
+  /// # Returns
+  /// The latest account, or `None` while the first answer is outstanding.
+  fn account(&mut self) -> Option<Account> {
+    if let Some(account) = Self::settle(&mut self.account_request) {
+      self.account = Some(account);
+    }
+    if self.account_request.is_none() {
+      self.account_request = Some(self.ask_for_account());
+    }
+    self.account.clone()
+  }
+
+  /// Tells the client to change the key.
+  ///
+  /// # Arguments
+  /// - `payload`: What to do with the key.
+  fn tell_client(&self, payload: Payload) {
+    let message = Message {
       source: Address::StateDisplay,
       destination: Address::IrxClient,
-      payload: Payload::Empty,
+      payload,
       tag: None,
       cacheable: Cacheable::No,
-      kind: Kind::Ask,
+      kind: Kind::Tell,
     };
+    if let Err(e) = self.router.send(message) {
+      log::error!("{e:?}");
+    }
+  }
 
-    self.message_tx_to_router.send(request)?;
-
-    let mut response_receiver = self
-      .message_rx_from_router
-      .take()
-      .ok_or_else(|| eyre!("failed to take ownership of receiver"))?;
-
-    let (api_key, receiver) = futures::executor::block_on(async {
-      handle
-        .spawn(async {
-          match response_receiver.recv().await {
-            Some(message) => match message.payload {
-              Payload::ApiKey(key) => Ok((key.to_string(), response_receiver)),
-              _ => Err(eyre!("invalid payload")),
-            },
-            None => Err(eyre!("no message received")),
-          }
-        })
-        .await?
-    })?;
-    self.message_rx_from_router = Some(receiver);
-    Ok(api_key)
+  /// Asks the client to replace the key with a new one.
+  pub fn regenerate_key(&mut self) {
+    self.revoke_requested = None;
+    self.tell_client(Payload::RegenerateKey);
+  }
+
+  /// Asks the client to revoke the key, once confirmed.
+  ///
+  /// The first call only asks for confirmation; calling again within
+  /// `CONFIRM` revokes the key.
+  pub fn revoke_key(&mut self) {
+    match self.revoke_requested.take() {
+      Some(requested) if requested.elapsed() < Self::CONFIRM => {
+        self.tell_client(Payload::RevokeKey);
+      }
+      _ => self.revoke_requested = Some(Instant::now()),
+    }
+  }
+
+  /// Shows the key in full, or masks it again.
+  pub fn toggle_reveal(&mut self) {
+    self.reveal = !self.reveal;
+  }
+
+  /// Masks the key again.
+  pub fn conceal(&mut self) {
+    self.reveal = false;
+  }
+
+  /// Returns whether revoking the key awaits confirmation.
+  fn revoke_pending(&self) -> bool {
+    self
+      .revoke_requested
+      .is_some_and(|requested| requested.elapsed() < Self::CONFIRM)
   }
 
   /// Retrieves the unbounded sender handle for the state display.
@@ -105,28 +246,76 @@ impl StateDisplay {
   /// # Returns
   /// A `Paragraph` widget configured to display the state information.
   fn state_display(&mut self) -> Result<Paragraph<'_>> {
-    let api_key = if let Some(key) = self.cache.get("api_key") {
-      key.clone()
-    } else {
-      let key = self.ask_for_key_sync(tokio::runtime::Handle::current())?;
-      self.cache.insert(String::from("api_key"), key.clone());
-      key
+    let client = match self.key_status() {
+      Some(status) => {
+        let value = match &status.key {
+          Some(key) if self.reveal => key.expose().to_string(),
+          Some(key) => key.to_string(),
+          None => String::from("none"),
+        };
+        let mut lines = vec![Line::from(format!(
+          "  Endpoint: {} ({})",
+          status.endpoint, status.connectivity
+        ))];
+        if let Some(problem) = status.network {
+          lines.push(Line::from(format!("    Network: {problem}").red()));
+        }
+        lines.extend([
+          Line::from("  API key:"),
+          Line::from(format!("    Profile: {}", status.profile)),
+          Line::from(format!("    Found: {}", status.source)),
+          Line::from(format!("    Path: {}", status.key_file.display())),
+          Line::from(format!("    Value: {value}")),
+        ]);
+        if let Some(warning) = status.warning {
+          lines.push(Line::from(format!("    {warning}").yellow()));
+        }
+        lines
+      }
+      None => vec![
+        Line::from("  Endpoint: ?"),
+        Line::from("  API key:"),
+        Line::from("    Profile: ?"),
+        Line::from("    Found: ?"),
+        Line::from("    Path: ?"),
+        Line::from("    Value: ?"),
+      ],
+    };
+    let account = self.account();
+    let (tier, session, lifetime) = match &account {
+      Some(account) => (
+        account.tier.clone().unwrap_or_else(|| String::from("?")),
+        account.session_requests.to_string(),
+        account.lifetime_requests.to_string(),
+      ),
+      None => (String::from("?"), String::from("?"), String::from("?")),
     };
-    // TODO: Don't render the key in plain text by default
     let time = chrono::Utc::now();
-    let text = vec![
+    let mut text = vec![
       Line::from(format!("{time}")),
       Line::from(""),
       Line::from("IRX Client:"),
-      Line::from(format!("  API key: {api_key}")),
-      Line::from("    Found: ?".to_string()),
-      Line::from("    Path: ?".to_string()),
-      Line::from("    Value: ?".to_string()),
-      Line::from("    Tier: ?".to_string()),
-      Line::from("  Requests:"),
-      Line::from("    This session: ?".to_string()),
-      Line::from("    Lifetime: ?".to_string()),
     ];
+    text.extend(client);
+    text.extend([
+      Line::from(format!("    Tier: {tier}")),
+      Line::from("  Requests:"),
+      Line::from(format!("    This session: {session}")),
+      Line::from(format!("    Lifetime: {lifetime}")),
+    ]);
+    if let Some(notice) = account.and_then(|account| account.notice) {
+      text.push(Line::from(""));
+      text.push(Line::from(format!("  {notice}")));
+    }
+    text.push(Line::from(""));
+    text.push(if self.revoke_pending() {
+      Line::from("  Press Ctrl-K again to revoke the key".yellow())
+    } else {
+      Line::from(
+        "  Ctrl-E: show/hide key, Ctrl-G: regenerate, Ctrl-K: revoke"
+          .dark_gray(),
+      )
+    });
     Ok(
       Paragraph::new(text)
         .block(
@@ -172,10 +361,60 @@ impl StateDisplay {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::irx_client::Connectivity;
+  use crate::router::Router;
+  use pretty_assertions::assert_eq;
 
-  #[test]
-  fn test_state_display_new() {
+  #[tokio::test]
+  async fn test_state_display_new() -> Result<()> {
     let (tx, _) = mpsc::unbounded_channel::<Message>();
-    StateDisplay::new(tx);
+    let (router, _) = Router::new(tx).await?;
+    StateDisplay::new(router.handle());
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_key_status_does_not_block() -> Result<()> {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let (mut router, router_tx) = Router::new(tx).await?;
+    let (client_tx, mut client_rx) = mpsc::unbounded_channel::<Message>();
+    router.register(Address::IrxClient, client_tx);
+    router.run();
+    let mut state_display = StateDisplay::new(router.handle());
+    assert_eq!(state_display.key_status(), None);
+
+    let question = client_rx.recv().await.expect("asked for the key");
+    let status = KeyStatus {
+      connectivity: Connectivity::Online,
+      ..KeyStatus::default()
+    };
+    router_tx.send(Message {
+      source: Address::IrxClient,
+      destination: question.source,
+      payload: Payload::KeyStatus(status.clone()),
+      tag: question.tag,
+      ..Message::default()
+    })?;
+    while state_display.key_status().is_none() {
+      tokio::task::yield_now().await;
+    }
+    assert_eq!(state_display.key_status(), Some(status));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_revoke_key_needs_confirmation() -> Result<()> {
+    let (router, mut router_rx) = RouterHandle::detached();
+    let mut state_display = StateDisplay::new(router);
+    state_display.revoke_key();
+    assert!(state_display.revoke_pending());
+    assert!(router_rx.try_recv().is_err());
+
+    state_display.revoke_key();
+    let message = router_rx.try_recv()?;
+    assert_eq!(message.destination, Address::IrxClient);
+    assert_eq!(message.payload, Payload::RevokeKey);
+    assert!(!state_display.revoke_pending());
+    Ok(())
   }
 }
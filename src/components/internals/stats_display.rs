@@ -1,6 +1,5 @@
 use super::buffers::Buffers;
 use super::counters::Counters;
-use crate::router::Message;
 use crate::{action::Action, tui::Frame};
 use color_eyre::eyre::Result;
 use ratatui::{
@@ -9,7 +8,6 @@ use ratatui::{
 };
 use ringbuffer::RingBuffer;
 use std::time::Instant;
-use tokio::sync::mpsc;
 use tui_tree_widget::{Tree, TreeItem, TreeState};
 
 // TODO: Detect when Render FPS < target FPS and adjust target accordingly
@@ -25,7 +23,6 @@ use tui_tree_widget::{Tree, TreeItem, TreeState};
 pub struct StatsDisplay {
   app_start_time: Instant,
   render_start_time: Instant,
-  pub message_tx_to_self: mpsc::UnboundedSender<Message>,
 }
 
 impl StatsDisplay {
@@ -33,11 +30,9 @@ impl StatsDisplay {
   ///
   /// Initializes the starting times for application and rendering FPS calculations.
   pub fn new() -> StatsDisplay {
-    let (message_tx_to_self, _) = mpsc::unbounded_channel::<Message>();
     StatsDisplay {
       app_start_time: Instant::now(),
       render_start_time: Instant::now(),
-      message_tx_to_self,
     }
   }
 
@@ -75,6 +70,14 @@ impl StatsDisplay {
           actions.change_view.push(counters.change_view);
           actions.toggle_overlay.push(counters.toggle_overlay);
           actions.help.push(counters.help);
+          actions.solve.push(counters.solve);
+          actions.solve_remote.push(counters.solve_remote);
+          actions.cancel.push(counters.cancel);
+          actions.regenerate_key.push(counters.regenerate_key);
+          actions.revoke_key.push(counters.revoke_key);
+          actions.reveal_key.push(counters.reveal_key);
+          actions.edit_params.push(counters.edit_params);
+          actions.edit_ensemble.push(counters.edit_ensemble);
         }
       }
       Action::Resize(_, _) => {
@@ -110,6 +113,30 @@ impl StatsDisplay {
       Action::Help => {
         counters.help = counters.help.saturating_add(1);
       }
+      Action::Solve => {
+        counters.solve = counters.solve.saturating_add(1);
+      }
+      Action::SolveRemote => {
+        counters.solve_remote = counters.solve_remote.saturating_add(1);
+      }
+      Action::Cancel => {
+        counters.cancel = counters.cancel.saturating_add(1);
+      }
+      Action::RegenerateKey => {
+        counters.regenerate_key = counters.regenerate_key.saturating_add(1);
+      }
+      Action::RevokeKey => {
+        counters.revoke_key = counters.revoke_key.saturating_add(1);
+      }
+      Action::RevealKey => {
+        counters.reveal_key = counters.reveal_key.saturating_add(1);
+      }
+      Action::EditParams => {
+        counters.edit_params = counters.edit_params.saturating_add(1);
+      }
+      Action::EditEnsemble => {
+        counters.edit_ensemble = counters.edit_ensemble.saturating_add(1);
+      }
     }
     actions.trail.push(format!(
       "{:?} {:?}",
@@ -180,10 +207,38 @@ impl StatsDisplay {
           format!("ToggleOverlay: {}", counters.toggle_overlay),
         ),
         TreeItem::new_leaf(14, format!("Help: {}", counters.help)),
+        TreeItem::new_leaf(15, format!("Solve: {}", counters.solve)),
+        TreeItem::new_leaf(16, format!("Cancel: {}", counters.cancel)),
+        TreeItem::new_leaf(
+          17,
+          format!("SolveRemote: {}", counters.solve_remote),
+        ),
+        TreeItem::new_leaf(
+          21,
+          format!("RegenerateKey: {}", counters.regenerate_key),
+        ),
+        TreeItem::new_leaf(22, format!("RevokeKey: {}", counters.revoke_key)),
+        TreeItem::new_leaf(23, format!("RevealKey: {}", counters.reveal_key)),
+        TreeItem::new_leaf(24, format!("EditParams: {}", counters.edit_params)),
+        TreeItem::new_leaf(
+          25,
+          format!("EditEnsemble: {}", counters.edit_ensemble),
+        ),
+      ],
+    )?;
+    let dead_letters = TreeItem::new(
+      18,
+      format!("Dead letters: {}", counters.dead_letters()),
+      vec![
+        TreeItem::new_leaf(
+          19,
+          format!("Unregistered: {}", counters.unregistered),
+        ),
+        TreeItem::new_leaf(20, format!("Closed: {}", counters.closed)),
       ],
     )?;
     //let root = TreeItem::new(0, "TUI", vec![actions])?;
-    let items = vec![nodes];
+    let items = vec![nodes, dead_letters];
     state.open(vec![1]);
     Ok((
       Tree::new(items)
@@ -244,6 +299,18 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_tree() -> Result<()> {
+    let counters = Counters {
+      unregistered: 2,
+      closed: 1,
+      ..Counters::default()
+    };
+    assert_eq!(counters.dead_letters(), 3);
+    StatsDisplay::tree(&counters)?;
+    Ok(())
+  }
+
   #[test]
   fn test_app_tick() {
     let mut display = StatsDisplay::new();
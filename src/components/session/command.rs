@@ -0,0 +1,367 @@
+use crate::job::{Gap, JobId, Location, Solver, SolverParams};
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  str::FromStr,
+};
+use strum::IntoEnumIterator;
+
+/// The commands and what they do, as listed by `help`.
+pub const USAGE: [(&str, &str); 5] = [
+  (
+    "open <file>",
+    "Load a problem, relative to the workspace root",
+  ),
+  (
+    "solve [--solver <name>] [--time-limit <s>] [--mip-gap <gap>] \
+     [--threads <n>] [--no-presolve] [--remote]",
+    "Solve the active problem",
+  ),
+  ("cancel [<job>]", "Cancel a job, or the selected one"),
+  (
+    "export <job> <path>",
+    "Write a job and its solver output as JSON",
+  ),
+  ("help", "List the commands"),
+];
+
+/// The names of the commands.
+const COMMANDS: [&str; 5] = ["open", "solve", "cancel", "export", "help"];
+
+/// The options `solve` takes.
+const SOLVE_OPTIONS: [&str; 6] = [
+  "--solver",
+  "--time-limit",
+  "--mip-gap",
+  "--threads",
+  "--no-presolve",
+  "--remote",
+];
+
+/// How to solve the active problem.
+///
+/// The solver and parameters left unset are taken from the active parameter
+/// set, or are the defaults when no set is active.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SolveArgs {
+  pub location: Location,
+  pub solver: Option<Solver>,
+  pub time_limit: Option<u64>,
+  pub mip_gap: Option<Gap>,
+  pub threads: Option<u32>,
+  pub presolve: Option<bool>,
+}
+
+impl SolveArgs {
+  /// Creates arguments that solve at a location with the active set.
+  pub fn at(location: Location) -> SolveArgs {
+    SolveArgs {
+      location,
+      ..SolveArgs::default()
+    }
+  }
+
+  /// Checks whether the solver or any parameter is given.
+  pub fn overrides(&self) -> bool {
+    self.solver.is_some()
+      || self.time_limit.is_some()
+      || self.mip_gap.is_some()
+      || self.threads.is_some()
+      || self.presolve.is_some()
+  }
+
+  /// Replaces parameters with the ones given.
+  pub fn apply(&self, params: &mut SolverParams) {
+    params.time_limit = self.time_limit.or(params.time_limit);
+    params.mip_gap = self.mip_gap.or(params.mip_gap);
+    params.threads = self.threads.or(params.threads);
+    params.presolve = self.presolve.unwrap_or(params.presolve);
+  }
+}
+
+/// A command typed at the Session prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+  /// Load a problem file.
+  Open(PathBuf),
+  /// Solve the active problem.
+  Solve(SolveArgs),
+  /// Cancel a job, or the selected job or ensemble if none is named.
+  Cancel(Option<JobId>),
+  /// Write a job and its solver output to a file.
+  Export(JobId, PathBuf),
+  /// List the commands.
+  Help,
+}
+
+impl FromStr for Command {
+  type Err = String;
+
+  /// Parses a command line, e.g. `solve --solver highs --time-limit 60`.
+  ///
+  /// Words may be quoted with `"`, for paths with spaces in them.
+  fn from_str(line: &str) -> Result<Self, Self::Err> {
+    let words = split(line)?;
+    let (name, args) = match words.split_first() {
+      Some((name, args)) => (name.as_str(), args),
+      None => return Err(String::from("no command given")),
+    };
+    let command = match (name, args) {
+      ("open", [path]) => Command::Open(PathBuf::from(path)),
+      ("open", _) => return Err(String::from("usage: open <file>")),
+      ("solve", args) => Command::Solve(solve_args(args)?),
+      ("cancel", []) => Command::Cancel(None),
+      ("cancel", [job]) => Command::Cancel(Some(job_id(job)?)),
+      ("cancel", _) => return Err(String::from("usage: cancel [<job>]")),
+      ("export", [job, path]) => {
+        Command::Export(job_id(job)?, PathBuf::from(path))
+      }
+      ("export", _) => return Err(String::from("usage: export <job> <path>")),
+      ("help", []) => Command::Help,
+      _ => return Err(format!("unknown command {name:?}, try help")),
+    };
+    Ok(command)
+  }
+}
+
+/// Splits a command line into words, keeping quoted words whole.
+fn split(line: &str) -> Result<Vec<String>, String> {
+  let mut words = Vec::new();
+  let mut word: Option<String> = None;
+  let mut quoted = false;
+  for c in line.chars() {
+    match c {
+      '"' => {
+        quoted = !quoted;
+        word.get_or_insert_with(String::new);
+      }
+      c if c.is_whitespace() && !quoted => words.extend(word.take()),
+      c => word.get_or_insert_with(String::new).push(c),
+    }
+  }
+  if quoted {
+    return Err(String::from("unclosed quote"));
+  }
+  words.extend(word);
+  Ok(words)
+}
+
+/// Parses a job identifier, written as `#3` or `3`.
+fn job_id(word: &str) -> Result<JobId, String> {
+  word
+    .strip_prefix('#')
+    .unwrap_or(word)
+    .parse()
+    .map(JobId)
+    .map_err(|_| format!("{word:?} is not a job, e.g. #3"))
+}
+
+/// Parses the options of `solve`.
+fn solve_args(args: &[String]) -> Result<SolveArgs, String> {
+  fn value<T: FromStr>(
+    option: &str,
+    value: Option<&String>,
+  ) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{option} needs a value"))?;
+    value
+      .parse()
+      .map_err(|_| format!("invalid {option} value {value:?}"))
+  }
+  let mut solve = SolveArgs::default();
+  let mut args = args.iter();
+  while let Some(option) = args.next() {
+    match option.as_str() {
+      "--solver" => {
+        let name = args.next().ok_or("--solver needs a value")?;
+        solve.solver = Some(name.parse()?);
+      }
+      "--time-limit" => solve.time_limit = Some(value(option, args.next())?),
+      "--mip-gap" => {
+        let gap = args.next().ok_or("--mip-gap needs a value")?;
+        solve.mip_gap = Some(gap.parse()?);
+      }
+      "--threads" => solve.threads = Some(value(option, args.next())?),
+      "--no-presolve" => solve.presolve = Some(false),
+      "--remote" => solve.location = Location::Remote,
+      _ => return Err(format!("unknown option {option:?}")),
+    }
+  }
+  Ok(solve)
+}
+
+/// Finds the ways the last word of a command line could be completed.
+///
+/// Commands, `solve` options, solver names, jobs, and file names are
+/// completed, depending on where the word is.
+///
+/// # Arguments
+/// - `line`: The command line typed so far.
+/// - `root`: The directory relative paths are completed in.
+/// - `jobs`: The jobs that can be named.
+///
+/// # Returns
+/// Where the last word starts in `line`, and the words it could be
+/// completed to, in order.
+pub fn complete(
+  line: &str,
+  root: &Path,
+  jobs: &[JobId],
+) -> (usize, Vec<String>) {
+  let start = line
+    .char_indices()
+    .rev()
+    .find(|(_, c)| c.is_whitespace())
+    .map_or(0, |(i, c)| i + c.len_utf8());
+  let word = &line[start..];
+  let before = line[..start].split_whitespace().collect::<Vec<_>>();
+  let jobs = || jobs.iter().map(JobId::to_string).collect::<Vec<_>>();
+  let candidates = match before.as_slice() {
+    [] => COMMANDS.iter().map(|c| c.to_string()).collect(),
+    ["open"] | ["export", _] => paths(root, word),
+    ["cancel"] | ["export"] => jobs(),
+    ["solve", .., "--solver"] => {
+      Solver::iter().map(|s| s.executable().to_string()).collect()
+    }
+    ["solve", options @ ..] => SOLVE_OPTIONS
+      .iter()
+      .filter(|option| !options.contains(option))
+      .map(|option| option.to_string())
+      .collect(),
+    _ => Vec::new(),
+  };
+  let candidates = candidates
+    .into_iter()
+    .filter(|candidate| candidate.starts_with(word))
+    .collect();
+  (start, candidates)
+}
+
+/// Lists the files and directories a partly typed path could name.
+///
+/// Directories end with `/`, and hidden entries are left out unless their
+/// name has been started.
+fn paths(root: &Path, word: &str) -> Vec<String> {
+  let (dir, prefix) = match word.rfind('/') {
+    Some(i) => word.split_at(i + 1),
+    None => ("", word),
+  };
+  let Ok(entries) = fs::read_dir(root.join(dir)) else {
+    return Vec::new();
+  };
+  let mut paths = entries
+    .flatten()
+    .filter_map(|entry| {
+      let name = entry.file_name().into_string().ok()?;
+      if !name.starts_with(prefix)
+        || (name.starts_with('.') && prefix.is_empty())
+      {
+        return None;
+      }
+      let slash = if entry.path().is_dir() { "/" } else { "" };
+      Some(format!("{dir}{name}{slash}"))
+    })
+    .collect::<Vec<_>>();
+  paths.sort();
+  paths
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use color_eyre::Result;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_command_parse() {
+    let command = "open \"miplib/my model.mps\"".parse::<Command>();
+    assert_eq!(command, Ok(Command::Open("miplib/my model.mps".into())));
+    let command = "solve --solver cbc --time-limit 60 --no-presolve --remote"
+      .parse::<Command>();
+    assert_eq!(
+      command,
+      Ok(Command::Solve(SolveArgs {
+        location: Location::Remote,
+        solver: Some(Solver::Cbc),
+        time_limit: Some(60),
+        presolve: Some(false),
+        ..SolveArgs::default()
+      }))
+    );
+    assert_eq!("cancel".parse(), Ok(Command::Cancel(None)));
+    assert_eq!("cancel #3".parse(), Ok(Command::Cancel(Some(JobId(3)))));
+    assert_eq!(
+      "export 2 out.json".parse(),
+      Ok(Command::Export(JobId(2), "out.json".into()))
+    );
+    assert_eq!("  help ".parse(), Ok(Command::Help));
+  }
+
+  #[test]
+  fn test_command_parse_errors() {
+    let error = |line: &str| line.parse::<Command>().unwrap_err();
+    assert_eq!(error(""), "no command given");
+    assert_eq!(error("run"), "unknown command \"run\", try help");
+    assert_eq!(error("open"), "usage: open <file>");
+    assert_eq!(error("open \"a.mps"), "unclosed quote");
+    assert_eq!(error("cancel three"), "\"three\" is not a job, e.g. #3");
+    assert_eq!(error("solve --time-limit"), "--time-limit needs a value");
+    assert_eq!(error("solve --threads x"), "invalid --threads value \"x\"");
+    assert_eq!(error("solve --solver gurobi"), "unknown solver \"gurobi\"");
+    assert_eq!(error("solve --fast"), "unknown option \"--fast\"");
+  }
+
+  #[test]
+  fn test_solve_args_apply() {
+    let args = SolveArgs {
+      time_limit: Some(60),
+      presolve: Some(false),
+      ..SolveArgs::default()
+    };
+    let mut params = SolverParams {
+      threads: Some(2),
+      ..SolverParams::default()
+    };
+    args.apply(&mut params);
+    assert_eq!(params.time_limit, Some(60));
+    assert_eq!(params.threads, Some(2));
+    assert!(!params.presolve);
+    assert!(args.overrides());
+    assert!(!SolveArgs::at(Location::Remote).overrides());
+  }
+
+  #[test]
+  fn test_complete() -> Result<()> {
+    let root = std::env::temp_dir()
+      .join(format!("napali-test-complete-{}", std::process::id()));
+    fs::create_dir_all(root.join("miplib"))?;
+    fs::write(root.join("miplib").join("afiro.mps"), "")?;
+    fs::write(root.join("model.mps"), "")?;
+    fs::write(root.join(".hidden"), "")?;
+    let jobs = [JobId(1), JobId(12)];
+    let complete = |line| complete(line, &root, &jobs);
+    assert_eq!(complete("s"), (0, vec![String::from("solve")]));
+    assert_eq!(
+      complete("open m"),
+      (5, vec![String::from("miplib/"), String::from("model.mps")])
+    );
+    assert_eq!(
+      complete("open miplib/"),
+      (5, vec![String::from("miplib/afiro.mps")])
+    );
+    assert_eq!(complete("cancel #1").1, ["#1", "#12"]);
+    assert_eq!(complete("export #1 mo").1, ["model.mps"]);
+    assert_eq!(complete("solve --solver ").1, ["highs", "cbc"]);
+    assert_eq!(
+      complete("solve --remote --t").1,
+      ["--time-limit", "--threads"]
+    );
+    assert_eq!(complete("help ").1, Vec::<String>::new());
+    assert_eq!(complete("cancel\u{3000}#1").0, 9);
+    assert_eq!(
+      complete("open\u{a0}mo"),
+      (6, vec![String::from("model.mps")])
+    );
+    fs::remove_dir_all(root)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,356 @@
+use super::params::Outcome;
+use crate::job::{Ensemble, Grid, Location, Solver};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+  prelude::*,
+  widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+use std::str::FromStr;
+use strum::{EnumIter, IntoEnumIterator};
+
+/// A field of the ensemble form, in the order they are shown.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, EnumIter)]
+enum Field {
+  #[default]
+  Name,
+  Solvers,
+  Location,
+  TimeLimits,
+  MipGaps,
+  Threads,
+  Presolve,
+}
+
+impl Field {
+  /// Returns the label shown next to the field.
+  fn label(self) -> &'static str {
+    match self {
+      Field::Name => "Name",
+      Field::Solvers => "Solvers",
+      Field::Location => "Run on",
+      Field::TimeLimits => "Time limits (s)",
+      Field::MipGaps => "MIP gaps",
+      Field::Threads => "Threads",
+      Field::Presolve => "Presolve",
+    }
+  }
+
+  /// Returns whether a character may be typed into the field.
+  fn accepts(self, c: char) -> bool {
+    match self {
+      Field::Name => !c.is_control(),
+      Field::TimeLimits | Field::Threads => c.is_ascii_digit() || c == ',',
+      Field::MipGaps => c.is_ascii_digit() || ".%, ".contains(c),
+      Field::Solvers | Field::Location | Field::Presolve => false,
+    }
+  }
+}
+
+/// The presolve settings an ensemble can try.
+const PRESOLVE: [(&str, &[bool]); 3] = [
+  ("on", &[]),
+  ("off", &[false]),
+  ("on and off", &[true, false]),
+];
+
+/// A form for configuring an ensemble run.
+///
+/// Solvers are ticked with Space, after picking one with Left and Right.
+/// The parameter fields take comma-separated lists of values, e.g.
+/// `60, 300`, and every combination of them is run with every ticked
+/// solver. The number of jobs this makes is shown as the form is filled in.
+#[derive(Debug, Default)]
+pub struct EnsembleForm {
+  is_active: bool,
+  field: Field,
+  name: String,
+  solvers: Vec<Solver>,
+  cursor: usize,
+  location: Location,
+  time_limits: String,
+  mip_gaps: String,
+  threads: String,
+  presolve: usize,
+  error: Option<String>,
+}
+
+impl EnsembleForm {
+  /// Constructs a new, inactive `EnsembleForm`.
+  pub fn new() -> Self {
+    EnsembleForm::default()
+  }
+
+  /// Opens the form, with the solvers found on `PATH` ticked.
+  ///
+  /// # Arguments
+  /// - `name`: The name the ensemble is given unless it's changed.
+  pub fn open(&mut self, name: String) {
+    *self = EnsembleForm {
+      is_active: true,
+      name,
+      solvers: Solver::iter().filter(|s| s.find().is_some()).collect(),
+      ..EnsembleForm::default()
+    };
+  }
+
+  /// Checks if the form is currently open.
+  pub fn is_active(&self) -> bool {
+    self.is_active
+  }
+
+  /// Returns the text of the field being edited, if it holds text.
+  fn text_mut(&mut self) -> Option<&mut String> {
+    match self.field {
+      Field::Name => Some(&mut self.name),
+      Field::TimeLimits => Some(&mut self.time_limits),
+      Field::MipGaps => Some(&mut self.mip_gaps),
+      Field::Threads => Some(&mut self.threads),
+      Field::Solvers | Field::Location | Field::Presolve => None,
+    }
+  }
+
+  /// Moves to the next field, or the previous one if `forward` is false.
+  fn step_field(&mut self, forward: bool) {
+    let fields = Field::iter().collect::<Vec<_>>();
+    let i = fields.iter().position(|&f| f == self.field).unwrap_or(0);
+    let n = fields.len();
+    self.field = fields[if forward {
+      (i + 1) % n
+    } else {
+      (i + n - 1) % n
+    }];
+  }
+
+  /// Cycles the choice in the current field.
+  fn step_choice(&mut self, forward: bool) {
+    let step = |i: usize, n: usize| {
+      if forward {
+        (i + 1) % n
+      } else {
+        (i + n - 1) % n
+      }
+    };
+    match self.field {
+      Field::Solvers => {
+        self.cursor = step(self.cursor, Solver::iter().count());
+      }
+      Field::Location => {
+        self.location = match self.location {
+          Location::Local => Location::Remote,
+          Location::Remote => Location::Local,
+        };
+      }
+      Field::Presolve => self.presolve = step(self.presolve, PRESOLVE.len()),
+      _ => {}
+    }
+  }
+
+  /// Ticks or unticks the solver under the cursor.
+  fn toggle_solver(&mut self) {
+    let Some(solver) = Solver::iter().nth(self.cursor) else {
+      return;
+    };
+    if self.solvers.contains(&solver) {
+      self.solvers.retain(|&s| s != solver);
+    } else {
+      self.solvers.push(solver);
+    }
+  }
+
+  /// Handles a key press while the form is open.
+  pub fn handle_key_event(&mut self, key: KeyEvent) -> Outcome<Ensemble> {
+    match key.code {
+      KeyCode::Esc => {
+        self.is_active = false;
+        return Outcome::Closed;
+      }
+      KeyCode::Enter => match self.parse() {
+        Ok(ensemble) => {
+          self.is_active = false;
+          return Outcome::Submitted(ensemble);
+        }
+        Err(e) => self.error = Some(e),
+      },
+      KeyCode::Up | KeyCode::BackTab => self.step_field(false),
+      KeyCode::Down | KeyCode::Tab => self.step_field(true),
+      KeyCode::Left => self.step_choice(false),
+      KeyCode::Right => self.step_choice(true),
+      KeyCode::Char(' ') if self.field == Field::Solvers => {
+        self.toggle_solver();
+        self.error = None;
+      }
+      KeyCode::Char(c) if self.field.accepts(c) => {
+        if let Some(text) = self.text_mut() {
+          text.push(c);
+          self.error = None;
+        }
+      }
+      KeyCode::Backspace => {
+        if let Some(text) = self.text_mut() {
+          text.pop();
+          self.error = None;
+        }
+      }
+      _ => {}
+    }
+    Outcome::Editing
+  }
+
+  /// Reads the ensemble from the form.
+  ///
+  /// # Returns
+  /// The ensemble, or a description of the first field that is invalid.
+  fn parse(&self) -> Result<Ensemble, String> {
+    fn list<T: FromStr>(field: Field, text: &str) -> Result<Vec<T>, String> {
+      Grid::parse_list(text).map_err(|e| format!("{}: {e}", field.label()))
+    }
+    // Solvers are launched in the order they are listed
+    let solvers = Solver::iter()
+      .filter(|solver| self.solvers.contains(solver))
+      .collect();
+    let ensemble = Ensemble {
+      name: self.name.trim().to_string(),
+      solvers,
+      grid: Grid {
+        time_limits: list(Field::TimeLimits, &self.time_limits)?,
+        mip_gaps: list(Field::MipGaps, &self.mip_gaps)?,
+        threads: list(Field::Threads, &self.threads)?,
+        presolve: PRESOLVE[self.presolve].1.to_vec(),
+      },
+      location: self.location,
+    };
+    ensemble.validate()?;
+    Ok(ensemble)
+  }
+
+  /// Renders the form as a popup centered in the given area.
+  pub fn render(&self, area: Rect, f: &mut Frame<'_>) {
+    if !self.is_active {
+      return;
+    }
+    let width = area.width.min(56);
+    let height = area.height.min(Field::iter().count() as u16 + 5);
+    let popup = Rect {
+      x: area.x + (area.width - width) / 2,
+      y: area.y + (area.height - height) / 2,
+      width,
+      height,
+    };
+    let mut lines = Field::iter()
+      .map(|field| {
+        let label = format!("{:<17}", field.label());
+        let label = if field == self.field {
+          label.bold()
+        } else {
+          label.into()
+        };
+        let mut spans = vec![label];
+        match field {
+          Field::Solvers => {
+            for (i, solver) in Solver::iter().enumerate() {
+              let tick = if self.solvers.contains(&solver) {
+                "x"
+              } else {
+                " "
+              };
+              let span = Span::from(format!("[{tick}] {solver}"));
+              spans.push(if field == self.field && i == self.cursor {
+                span.reversed()
+              } else {
+                span
+              });
+              spans.push(Span::from(" "));
+            }
+          }
+          _ => {
+            let value = match field {
+              Field::Name => self.name.clone(),
+              Field::Location => format!("< {:?} >", self.location),
+              Field::TimeLimits => self.time_limits.clone(),
+              Field::MipGaps => self.mip_gaps.clone(),
+              Field::Threads => self.threads.clone(),
+              _ => format!("< {} >", PRESOLVE[self.presolve].0),
+            };
+            spans.push(if field == self.field {
+              value.reversed()
+            } else {
+              value.into()
+            });
+          }
+        }
+        Line::from(spans)
+      })
+      .collect::<Vec<_>>();
+    lines.push(Line::default());
+    lines.push(match (&self.error, self.parse()) {
+      (Some(e), _) => Line::from(e.clone().red()),
+      (None, Ok(ensemble)) => Line::from(
+        format!("Enter launches {} jobs · Esc close", ensemble.count())
+          .dark_gray(),
+      ),
+      (None, Err(e)) => Line::from(e.dark_gray()),
+    });
+    let block = Block::default()
+      .title("Configure Ensemble Run")
+      .title_alignment(Alignment::Left)
+      .borders(Borders::ALL)
+      .border_type(BorderType::Rounded)
+      .border_style(Style::default().fg(Color::LightGreen));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crossterm::event::KeyModifiers;
+  use pretty_assertions::assert_eq;
+
+  fn press(form: &mut EnsembleForm, code: KeyCode) -> Outcome<Ensemble> {
+    form.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+  }
+
+  fn type_text(form: &mut EnsembleForm, text: &str) {
+    for c in text.chars() {
+      press(form, KeyCode::Char(c));
+    }
+  }
+
+  #[test]
+  fn test_ensemble_form_launches_grid() {
+    let mut form = EnsembleForm::new();
+    form.open(String::from("ensemble-1"));
+    form.solvers.clear();
+    press(&mut form, KeyCode::Down);
+    press(&mut form, KeyCode::Char(' '));
+    press(&mut form, KeyCode::Right);
+    press(&mut form, KeyCode::Char(' '));
+    press(&mut form, KeyCode::Down);
+    press(&mut form, KeyCode::Right);
+    press(&mut form, KeyCode::Down);
+    type_text(&mut form, "60, 300");
+    press(&mut form, KeyCode::Up);
+    press(&mut form, KeyCode::Up);
+    press(&mut form, KeyCode::Char(' '));
+    let Outcome::Submitted(ensemble) = press(&mut form, KeyCode::Enter) else {
+      panic!("the form is valid");
+    };
+    assert!(!form.is_active());
+    assert_eq!(ensemble.name, "ensemble-1");
+    assert_eq!(ensemble.solvers, vec![Solver::Highs]);
+    assert_eq!(ensemble.location, Location::Remote);
+    assert_eq!(ensemble.grid.time_limits, vec![60, 300]);
+    assert_eq!(ensemble.runs().len(), 2);
+  }
+
+  #[test]
+  fn test_ensemble_form_needs_a_solver() {
+    let mut form = EnsembleForm::new();
+    form.open(String::from("ensemble-1"));
+    form.solvers.clear();
+    assert_eq!(press(&mut form, KeyCode::Enter), Outcome::Editing);
+    assert_eq!(form.error.as_deref(), Some("pick at least one solver"));
+    assert_eq!(press(&mut form, KeyCode::Esc), Outcome::Closed);
+  }
+}
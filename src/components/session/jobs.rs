@@ -1,7 +1,16 @@
 use crate::action::view;
+use crate::irx_client::Connectivity;
+use crate::job::{Job, JobId, JobState, Location, Solution};
 use ratatui::{
   prelude::*,
-  widgets::{block::Block, BorderType, Borders, Tabs},
+  widgets::{
+    block::{Block, Title},
+    BorderType, Borders, Cell, List, ListItem, ListState, Row, Table, Tabs,
+  },
+};
+use std::{
+  collections::HashMap,
+  time::{Duration, Instant},
 };
 
 /// Represents different views that can be displayed in the Jobs section.
@@ -25,6 +34,17 @@ impl From<View> for usize {
   }
 }
 
+impl View {
+  /// Returns whether a job belongs under this view's tab.
+  fn shows(&self, job: &Job) -> bool {
+    match self {
+      View::Remote => job.location == Location::Remote,
+      View::Local => job.location == Location::Local,
+      View::All | View::Prompt => true,
+    }
+  }
+}
+
 impl From<view::View> for View {
   fn from(k: view::View) -> View {
     match k {
@@ -36,11 +56,32 @@ impl From<view::View> for View {
   }
 }
 
+/// An entry of the job list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry<'j> {
+  /// The header above the jobs of an ensemble.
+  Ensemble(&'j str),
+  Job(&'j Job),
+}
+
 /// Manages and displays a tab bar for different job views in a TUI application.
+///
+/// Below the tab bar, the jobs belonging to the current view are listed in
+/// submission order along with their solver, parameter set and state. The
+/// jobs of an ensemble are listed together under a header, and selecting
+/// the header shows their results side by side. When remote solving is
+/// unavailable, the pane says so in its title.
 #[derive(Debug)]
 pub struct Jobs<'a> {
   block: Block<'a>,
   view: View,
+  jobs: Vec<Job>,
+  state: ListState,
+  focused: bool,
+  remote: Connectivity,
+  /// When each job still running started, or was submitted if it hasn't
+  /// been seen running yet.
+  started: HashMap<JobId, Instant>,
 }
 
 impl<'a> Jobs<'a> {
@@ -54,9 +95,269 @@ impl<'a> Jobs<'a> {
         .border_style(Style::default())
         .border_type(BorderType::Rounded),
       view: View::default(),
+      jobs: Vec::new(),
+      state: ListState::default(),
+      focused: false,
+      remote: Connectivity::default(),
+      started: HashMap::new(),
+    }
+  }
+
+  /// Sets whether remote jobs can be submitted.
+  pub fn set_remote_status(&mut self, remote: Connectivity) {
+    self.remote = remote;
+  }
+
+  /// Adds a job to the list and selects it.
+  pub fn push(&mut self, job: Job) {
+    self.started.insert(job.id(), Instant::now());
+    self.jobs.push(job);
+    self.select_last();
+  }
+
+  /// Updates the state of a job, timing it as it starts and stops.
+  ///
+  /// # Returns
+  /// The updated job, or `None` if no job has the given identifier.
+  pub fn set_state(&mut self, id: JobId, state: JobState) -> Option<&Job> {
+    let job = self.jobs.iter_mut().find(|job| job.id() == id)?;
+    if state == JobState::Running && job.state == JobState::Queued {
+      self.started.insert(id, Instant::now());
+    }
+    if state.is_done() {
+      job.elapsed = self.started.remove(&id).map(|t| t.elapsed());
+    }
+    job.state = state;
+    Some(job)
+  }
+
+  /// Records what the solver of a job found.
+  pub fn set_solution(&mut self, id: JobId, solution: Solution) {
+    if let Some(job) = self.jobs.iter_mut().find(|job| job.id() == id) {
+      job.solution = Some(solution);
+    }
+  }
+
+  /// Returns the jobs shown under the current view.
+  fn visible(&self) -> impl Iterator<Item = &Job> {
+    self.jobs.iter().filter(|job| self.view.shows(job))
+  }
+
+  /// Returns the job with the given identifier, if any.
+  pub fn get(&self, id: JobId) -> Option<&Job> {
+    self.jobs.iter().find(|job| job.id() == id)
+  }
+
+  /// Returns the identifiers of all jobs, in the order they were added.
+  pub fn ids(&self) -> Vec<JobId> {
+    self.jobs.iter().map(Job::id).collect()
+  }
+
+  /// Returns the jobs of an ensemble.
+  pub fn ensemble(&self, name: &str) -> impl Iterator<Item = &Job> {
+    let name = name.to_string();
+    self
+      .jobs
+      .iter()
+      .filter(move |job| job.ensemble.as_ref() == Some(&name))
+  }
+
+  /// Returns the entries of the list under the current view.
+  ///
+  /// The jobs of an ensemble follow its header, wherever the first of them
+  /// would otherwise be listed.
+  fn entries(&self) -> Vec<Entry<'_>> {
+    let mut entries = Vec::new();
+    let mut listed: Vec<&str> = Vec::new();
+    for job in self.visible() {
+      match job.ensemble.as_deref() {
+        None => entries.push(Entry::Job(job)),
+        Some(name) if listed.contains(&name) => {}
+        Some(name) => {
+          listed.push(name);
+          entries.push(Entry::Ensemble(name));
+          entries.extend(
+            self
+              .visible()
+              .filter(|j| j.ensemble.as_deref() == Some(name))
+              .map(Entry::Job),
+          );
+        }
+      }
+    }
+    entries
+  }
+
+  /// Returns the selected job, if any.
+  pub fn selected(&self) -> Option<&Job> {
+    match self.entries().get(self.state.selected()?) {
+      Some(Entry::Job(job)) => Some(job),
+      _ => None,
+    }
+  }
+
+  /// Returns how long a job has been running, or ran for once stopped.
+  pub fn runtime(&self, job: &Job) -> Option<Duration> {
+    if job.state == JobState::Running {
+      return self.started.get(&job.id()).map(|t| t.elapsed());
+    }
+    job.elapsed
+  }
+
+  /// Returns the name of the selected ensemble, if its header is selected.
+  pub fn selected_ensemble(&self) -> Option<String> {
+    match self.entries().get(self.state.selected()?) {
+      Some(Entry::Ensemble(name)) => Some(name.to_string()),
+      _ => None,
     }
   }
 
+  /// Selects the last entry of the list.
+  fn select_last(&mut self) {
+    let count = self.entries().len();
+    self.state.select(count.checked_sub(1));
+  }
+
+  /// Selects the header of an ensemble.
+  pub fn select_ensemble(&mut self, name: &str) {
+    let i = self
+      .entries()
+      .iter()
+      .position(|entry| *entry == Entry::Ensemble(name));
+    if i.is_some() {
+      self.state.select(i);
+    }
+  }
+
+  /// Sets whether the job list has keyboard focus.
+  pub fn set_focused(&mut self, focused: bool) {
+    self.focused = focused;
+  }
+
+  /// Moves the selection up.
+  pub fn key_up(&mut self) {
+    let i = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+    self.state.select(Some(i));
+  }
+
+  /// Moves the selection down.
+  pub fn key_down(&mut self) {
+    let last = self.entries().len().saturating_sub(1);
+    let i = self.state.selected().map_or(0, |i| (i + 1).min(last));
+    self.state.select(Some(i));
+  }
+
+  /// Styles a job state with its color.
+  fn state_span(state: JobState) -> Span<'static> {
+    let text = state.to_string();
+    match state {
+      JobState::Queued => text.dark_gray(),
+      JobState::Running => text.cyan(),
+      JobState::Finished => text.green(),
+      JobState::Failed => text.red(),
+      JobState::Cancelled => text.yellow(),
+    }
+  }
+
+  /// Builds the list widget for the jobs shown under the current view.
+  fn list_widget(&self) -> List<'a> {
+    let items = self
+      .entries()
+      .into_iter()
+      .map(|entry| match entry {
+        Entry::Ensemble(name) => {
+          let (total, done) =
+            self.ensemble(name).fold((0, 0), |(total, done), job| {
+              (total + 1, done + usize::from(job.state.is_done()))
+            });
+          let progress = format!("{done}/{total} done");
+          ListItem::new(Line::from(vec![
+            format!("▾ {name} ").bold(),
+            if done == total {
+              progress.green()
+            } else {
+              progress.cyan()
+            },
+          ]))
+        }
+        Entry::Job(job) => {
+          let indent = if job.ensemble.is_some() { "  " } else { "" };
+          ListItem::new(Line::from(vec![
+            format!("{indent}{} ", job.id()).dark_gray(),
+            format!("{} ", job.name).into(),
+            match &job.spec.param_set {
+              Some(set) => format!("{} ({set}) ", job.spec.solver).dark_gray(),
+              None => format!("{} ", job.spec.solver).dark_gray(),
+            },
+            Self::state_span(job.state),
+          ]))
+        }
+      })
+      .collect::<Vec<_>>();
+    let highlight = if self.focused {
+      Style::default().bold().reversed()
+    } else {
+      Style::default().bold()
+    };
+    List::new(items).highlight_style(highlight)
+  }
+
+  /// Builds a table comparing the results of an ensemble's jobs.
+  fn results_widget(&self, name: &str) -> Table<'a> {
+    let rows = self
+      .ensemble(name)
+      .map(|job| {
+        let solution = job.solution.as_ref();
+        Row::new(vec![
+          Cell::from(job.id().to_string()),
+          Cell::from(job.spec.solver.to_string()),
+          Cell::from(job.spec.params.to_string()),
+          Cell::from(Self::state_span(job.state)),
+          Cell::from(solution.map_or(String::from("-"), |s| s.status.clone())),
+          Cell::from(
+            solution
+              .and_then(|s| s.objective)
+              .map_or(String::from("-"), |o| o.to_string()),
+          ),
+          Cell::from(
+            job.elapsed.map_or(String::from("-"), |t| {
+              format!("{:.1}s", t.as_secs_f64())
+            }),
+          ),
+        ])
+      })
+      .collect::<Vec<_>>();
+    Table::new(
+      rows,
+      [
+        Constraint::Length(5),
+        Constraint::Length(6),
+        Constraint::Min(12),
+        Constraint::Length(9),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(8),
+      ],
+    )
+    .header(
+      Row::new(vec![
+        "Job",
+        "Solver",
+        "Params",
+        "State",
+        "Status",
+        "Objective",
+        "Time",
+      ])
+      .style(Style::default().bold()),
+    )
+    .block(
+      Block::default()
+        .title(format!("Results: {name}"))
+        .borders(Borders::TOP),
+    )
+  }
+
   /// Creates a tab bar widget based on the current view.
   fn tab_bar_widget(&self) -> Tabs<'a> {
     let job_tab_titles = match self.view {
@@ -81,7 +382,7 @@ impl<'a> Jobs<'a> {
   }
 
   /// Calculates layout areas for different parts of the Jobs display.
-  fn layers(area: Rect) -> (Rect, Rect) {
+  fn layers(area: Rect) -> (Rect, Rect, Rect) {
     let jobs_bar = Layout::default()
       .direction(Direction::Vertical)
       .constraints(vec![Constraint::Max(3), Constraint::Min(1)])
@@ -89,28 +390,52 @@ impl<'a> Jobs<'a> {
         horizontal: 1,
         vertical: 1,
       }));
-    (area, jobs_bar[0])
+    (area, jobs_bar[0], jobs_bar[1])
   }
 
   /// Sets the current view for the Jobs display.
   pub fn set_view(&mut self, k: view::View) {
     self.view = View::from(k);
+    self.select_last();
   }
 
   /// Renders the Jobs display in the specified area of the frame.
+  ///
+  /// With an ensemble selected, the lower half of the pane compares the
+  /// results of its jobs.
   pub fn render(&mut self, area: Rect, f: &mut Frame<'_>) {
-    let (main_area, tab_bar_area) = Self::layers(area);
+    let (main_area, tab_bar_area, list_area) = Self::layers(area);
     let tab_bar = self.tab_bar_widget();
+    let list = self.list_widget();
 
-    // Render the main block and the tab bar in their respective areas
-    f.render_widget(self.block.clone(), main_area);
+    // Render the main block, the tab bar and the job list in their respective areas
+    let block = match self.remote {
+      Connectivity::Online => self.block.clone(),
+      remote => self.block.clone().title(
+        Title::from(format!(" Remote: {remote} ").dark_gray())
+          .alignment(Alignment::Right),
+      ),
+    };
+    f.render_widget(block, main_area);
     f.render_widget(tab_bar, tab_bar_area);
+    match self.selected_ensemble() {
+      Some(name) => {
+        let halves = Layout::default()
+          .direction(Direction::Vertical)
+          .constraints(vec![Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+          .split(list_area);
+        f.render_stateful_widget(list, halves[0], &mut self.state);
+        f.render_widget(self.results_widget(&name), halves[1]);
+      }
+      None => f.render_stateful_widget(list, list_area, &mut self.state),
+    }
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::job::JobSpec;
   use pretty_assertions::assert_eq;
 
   #[test]
@@ -143,4 +468,58 @@ mod tests {
     jobs.set_view(view::View::Prompt);
     assert_eq!(jobs.view, View::Prompt);
   }
+
+  #[test]
+  fn test_jobs_filter_by_view() {
+    let mut jobs = Jobs::new();
+    for (id, location) in [(1, Location::Local), (2, Location::Remote)] {
+      let spec = JobSpec {
+        id: JobId(id),
+        ..JobSpec::default()
+      };
+      jobs.push(Job::new(spec, String::from("afiro"), location));
+    }
+    assert_eq!(jobs.selected().map(Job::id), Some(JobId(2)));
+    jobs.set_view(view::View::L);
+    assert_eq!(jobs.selected().map(Job::id), Some(JobId(1)));
+    jobs.key_down();
+    assert_eq!(jobs.selected().map(Job::id), Some(JobId(1)));
+    let job = jobs.set_state(JobId(1), JobState::Running);
+    assert_eq!(job.map(|job| job.state), Some(JobState::Running));
+    let job = jobs.selected().unwrap();
+    assert!(jobs.runtime(job).is_some());
+    assert!(jobs.set_state(JobId(3), JobState::Running).is_none());
+  }
+
+  #[test]
+  fn test_jobs_group_ensembles() {
+    let mut jobs = Jobs::new();
+    for (id, ensemble) in [(1, Some("e")), (2, None), (3, Some("e"))] {
+      let spec = JobSpec {
+        id: JobId(id),
+        ..JobSpec::default()
+      };
+      let mut job = Job::new(spec, String::from("afiro"), Location::Local);
+      job.ensemble = ensemble.map(String::from);
+      jobs.push(job);
+    }
+    let ids = |jobs: &Jobs| {
+      jobs
+        .entries()
+        .iter()
+        .map(|entry| match entry {
+          Entry::Ensemble(name) => name.to_string(),
+          Entry::Job(job) => job.id().to_string(),
+        })
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&jobs), ["e", "#1", "#3", "#2"]);
+    assert_eq!(jobs.selected().map(Job::id), Some(JobId(2)));
+    jobs.select_ensemble("e");
+    assert_eq!(jobs.selected(), None);
+    assert_eq!(jobs.selected_ensemble().as_deref(), Some("e"));
+    jobs.set_state(JobId(3), JobState::Finished);
+    let job = jobs.ensemble("e").find(|job| job.id() == JobId(3));
+    assert!(job.and_then(|job| job.elapsed).is_some());
+  }
 }
@@ -0,0 +1,524 @@
+use crate::job::JobId;
+use crossterm::event::{KeyCode, KeyEvent};
+use log::Level;
+use ratatui::{
+  prelude::*,
+  widgets::{block::Block, BorderType, Borders, Paragraph},
+};
+use std::{collections::VecDeque, fmt};
+use tui_textarea::TextArea;
+
+/// The most records kept for scrolling back through.
+const MAX_RECORDS: usize = 10_000;
+
+/// Where a log record came from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+  /// Napali itself.
+  Napali,
+  /// A job's solver.
+  Job(JobId),
+}
+
+impl fmt::Display for Source {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Source::Napali => write!(f, "napali"),
+      Source::Job(id) => write!(f, "job {id}"),
+    }
+  }
+}
+
+/// A line in the Logs pane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Record {
+  source: Source,
+  level: Level,
+  message: String,
+}
+
+/// Represents the Logs section in a TUI application.
+///
+/// Records come from napali itself, including its `tracing` output, and
+/// from the solvers of running jobs. The pane follows the latest records
+/// until it's scrolled back, and shows only the records at or above a
+/// level, from one source or all of them. `/` searches the records with a
+/// regular expression, highlighting the matches; `n` and `N` step through
+/// them.
+#[derive(Debug)]
+pub struct Logs<'a> {
+  pub block: Block<'a>,
+  records: VecDeque<Record>,
+  /// The least severe level shown.
+  level: Level,
+  /// The source shown, or `None` for all of them.
+  source: Option<Source>,
+  /// Sources seen so far, in the order they were first seen.
+  sources: Vec<Source>,
+  /// Position of the top line among the shown records, or `None` to
+  /// follow the latest records.
+  top: Option<usize>,
+  /// Number of lines that fit in the pane when it was last rendered.
+  height: usize,
+  focused: bool,
+  /// The search being typed, which also holds the pattern searched for.
+  query: TextArea<'a>,
+  searching: bool,
+  /// Position of the current match among the shown records.
+  current: Option<usize>,
+  error: Option<String>,
+}
+
+impl<'a> Logs<'a> {
+  /// Constructs a new `Logs` instance with default settings.
+  pub fn new() -> Logs<'a> {
+    Logs {
+      // Titled when rendered, after the filters and search
+      block: Block::default()
+        .title_alignment(Alignment::Left)
+        .borders(Borders::ALL)
+        .border_style(Style::default())
+        .border_type(BorderType::Rounded),
+      records: VecDeque::new(),
+      level: Level::Trace,
+      source: None,
+      sources: vec![Source::Napali],
+      top: None,
+      height: 0,
+      focused: false,
+      query: Self::query(),
+      searching: false,
+      current: None,
+      error: None,
+    }
+  }
+
+  /// Creates an empty search field.
+  fn query() -> TextArea<'a> {
+    let mut query = TextArea::default();
+    query.set_cursor_line_style(Style::default());
+    query.set_search_style(Style::default());
+    query.set_placeholder_text("regular expression");
+    query
+  }
+
+  /// Appends a record from napali to the log.
+  pub fn push(&mut self, level: Level, message: impl Into<String>) {
+    self.append(Source::Napali, level, message.into());
+  }
+
+  /// Appends a line of a job's solver output to the log.
+  pub fn push_job(&mut self, id: JobId, line: impl Into<String>) {
+    self.append(Source::Job(id), Level::Info, line.into());
+  }
+
+  fn append(&mut self, source: Source, level: Level, message: String) {
+    if !self.sources.contains(&source) {
+      self.sources.push(source);
+    }
+    self.records.push_back(Record {
+      source,
+      level,
+      message,
+    });
+    if self.records.len() > MAX_RECORDS {
+      let dropped = self.records.pop_front();
+      // Keep the view on the same records as the oldest one scrolls off
+      if dropped.is_some_and(|record| self.shows(&record)) {
+        self.top = self.top.map(|top| top.saturating_sub(1));
+        self.current = self.current.and_then(|i| i.checked_sub(1));
+      }
+    }
+  }
+
+  /// Returns the lines of a job's solver output still held.
+  pub fn output(&self, id: JobId) -> Vec<&str> {
+    self
+      .records
+      .iter()
+      .filter(|record| record.source == Source::Job(id))
+      .map(|record| record.message.as_str())
+      .collect()
+  }
+
+  /// Checks whether a record passes the level and source filters.
+  fn shows(&self, record: &Record) -> bool {
+    record.level <= self.level
+      && (self.source.is_none() || self.source == Some(record.source))
+  }
+
+  /// Returns the records that pass the filters.
+  fn shown(&self) -> Vec<&Record> {
+    self.records.iter().filter(|r| self.shows(r)).collect()
+  }
+
+  /// Returns the position of the top line among the shown records.
+  fn top(&self, shown: usize) -> usize {
+    let last_page = shown.saturating_sub(self.height);
+    self.top.map_or(last_page, |top| top.min(last_page))
+  }
+
+  /// Sets whether the pane has keyboard focus.
+  pub fn set_focused(&mut self, focused: bool) {
+    self.focused = focused;
+  }
+
+  /// Checks if a search is being typed.
+  pub fn is_searching(&self) -> bool {
+    self.searching
+  }
+
+  /// Checks if the pane follows the latest records.
+  pub fn is_following(&self) -> bool {
+    self.top.is_none()
+  }
+
+  /// Scrolls back by `lines`, leaving follow mode.
+  fn scroll_up(&mut self, lines: usize) {
+    let top = self.top(self.shown().len());
+    self.top = Some(top.saturating_sub(lines));
+  }
+
+  /// Scrolls forward by `lines`, following again at the end of the log.
+  fn scroll_down(&mut self, lines: usize) {
+    let shown = self.shown().len();
+    let top = self.top(shown) + lines;
+    self.top = (top < shown.saturating_sub(self.height)).then_some(top);
+  }
+
+  /// Hides the least severe level shown, or shows all levels again once
+  /// only errors are.
+  fn cycle_level(&mut self) {
+    self.level = match self.level {
+      Level::Trace => Level::Debug,
+      Level::Debug => Level::Info,
+      Level::Info => Level::Warn,
+      Level::Warn => Level::Error,
+      Level::Error => Level::Trace,
+    };
+    self.reset_view();
+  }
+
+  /// Steps through the sources, with all of them before the first.
+  fn cycle_source(&mut self, forward: bool) {
+    let n = self.sources.len() + 1;
+    let i = self
+      .source
+      .and_then(|s| self.sources.iter().position(|&t| t == s))
+      .map_or(0, |i| i + 1);
+    let i = if forward {
+      (i + 1) % n
+    } else {
+      (i + n - 1) % n
+    };
+    self.source = i.checked_sub(1).map(|i| self.sources[i]);
+    self.reset_view();
+  }
+
+  /// Follows the latest records, e.g. after the filters change.
+  fn reset_view(&mut self) {
+    self.top = None;
+    self.current = None;
+  }
+
+  /// Runs the search typed into the search field.
+  ///
+  /// An empty search clears the pattern; an invalid one is reported and
+  /// left in the field to be fixed.
+  fn run_search(&mut self) {
+    let text = self.query.lines()[0].clone();
+    match self.query.set_search_pattern(&text) {
+      Ok(()) => {
+        self.searching = false;
+        self.error = None;
+        self.current = None;
+        self.find(false);
+      }
+      // The last line of the error says what's wrong with the pattern
+      Err(e) => self.error = e.to_string().lines().last().map(String::from),
+    }
+  }
+
+  /// Moves to the next match, or the previous one if `forward` is false.
+  ///
+  /// The first search starts from the end of the log, so that it finds
+  /// the latest match.
+  fn find(&mut self, forward: bool) {
+    let Some(pattern) = self.query.search_pattern() else {
+      return;
+    };
+    let shown = self.shown();
+    let matches = |i: &usize| pattern.is_match(&shown[*i].message);
+    let found = match (self.current, forward) {
+      (None, _) => (0..shown.len()).rev().find(matches),
+      (Some(i), true) => (i + 1..shown.len()).find(matches),
+      (Some(i), false) => (0..i).rev().find(matches),
+    };
+    let Some(found) = found else {
+      return;
+    };
+    let top = self.top(shown.len());
+    self.current = Some(found);
+    if found < top || found >= top + self.height {
+      self.top = Some(found.saturating_sub(self.height / 2));
+    }
+  }
+
+  /// Handles a key press while the pane has focus or a search is typed.
+  pub fn handle_key_event(&mut self, key: KeyEvent) {
+    if self.searching {
+      match key.code {
+        KeyCode::Enter => self.run_search(),
+        KeyCode::Esc => {
+          self.searching = false;
+          self.error = None;
+        }
+        _ => {
+          self.query.input(key);
+          self.error = None;
+        }
+      }
+      return;
+    }
+    let page = self.height.max(1);
+    match key.code {
+      KeyCode::Up => self.scroll_up(1),
+      KeyCode::Down => self.scroll_down(1),
+      KeyCode::PageUp => self.scroll_up(page),
+      KeyCode::PageDown => self.scroll_down(page),
+      KeyCode::Home => self.top = Some(0),
+      KeyCode::End => self.top = None,
+      KeyCode::Char('f') => {
+        self.top = match self.top {
+          Some(_) => None,
+          None => Some(self.top(self.shown().len())),
+        }
+      }
+      KeyCode::Char('v') => self.cycle_level(),
+      KeyCode::Char(']') => self.cycle_source(true),
+      KeyCode::Char('[') => self.cycle_source(false),
+      KeyCode::Char('/') => {
+        self.query = Self::query();
+        self.searching = true;
+      }
+      KeyCode::Char('n') => self.find(true),
+      KeyCode::Char('N') => self.find(false),
+      KeyCode::Esc => {
+        self.query.set_search_pattern("").ok();
+        self.current = None;
+      }
+      _ => {}
+    }
+  }
+
+  /// Builds the pane's title from its filters and search.
+  fn title(&self, shown: &[&Record]) -> Line<'a> {
+    let source = self.source.map_or(String::from("all"), |s| s.to_string());
+    let mut spans = vec![
+      Span::from("Logs · "),
+      Span::from(source),
+      Span::from(format!(" · {}+", self.level.as_str().to_lowercase())),
+    ];
+    if !self.is_following() {
+      spans.push(" · paused".yellow());
+    }
+    if let Some(pattern) = self.query.search_pattern() {
+      let count = shown
+        .iter()
+        .filter(|r| pattern.is_match(&r.message))
+        .count();
+      spans.push(Span::from(format!(" · /{} ", pattern.as_str())));
+      spans.push(match self.current {
+        _ if count == 0 => "(no matches)".red(),
+        Some(i) => {
+          let n = shown[..=i]
+            .iter()
+            .filter(|r| pattern.is_match(&r.message))
+            .count();
+          Span::from(format!("({n}/{count})"))
+        }
+        None => Span::from(format!("({count})")),
+      });
+    }
+    Line::from(spans)
+  }
+
+  /// Builds the line showing a record, with any matches highlighted.
+  fn line(&self, record: &Record, is_current: bool) -> Line<'a> {
+    let style = match record.level {
+      Level::Error => Style::default().fg(Color::Red),
+      Level::Warn => Style::default().fg(Color::Yellow),
+      Level::Debug | Level::Trace => Style::default().fg(Color::DarkGray),
+      Level::Info => Style::default(),
+    };
+    let mut spans = Vec::new();
+    if let (None, Source::Job(id)) = (self.source, record.source) {
+      spans.push(Span::styled(format!("[{id}] "), style));
+    }
+    let message = &record.message;
+    let mut end = 0;
+    if let Some(pattern) = self.query.search_pattern() {
+      let highlight = if is_current {
+        Style::default()
+          .fg(Color::Black)
+          .bg(Color::LightYellow)
+          .bold()
+      } else {
+        Style::default().fg(Color::Black).bg(Color::Yellow)
+      };
+      for m in pattern.find_iter(message).filter(|m| !m.is_empty()) {
+        spans.push(Span::styled(message[end..m.start()].to_string(), style));
+        spans.push(Span::styled(m.as_str().to_string(), highlight));
+        end = m.end();
+      }
+    }
+    spans.push(Span::styled(message[end..].to_string(), style));
+    Line::from(spans)
+  }
+
+  /// Renders the shown records that fit in the specified area.
+  pub fn render(&mut self, area: Rect, f: &mut Frame<'_>) {
+    let border = if self.focused {
+      Style::default().fg(Color::LightGreen)
+    } else {
+      Style::default()
+    };
+    let block = self.block.clone().border_style(border);
+    let inner = block.inner(area);
+    let (list_area, search_area) = if self.searching {
+      let areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+      (areas[0], Some(areas[1]))
+    } else {
+      (inner, None)
+    };
+    self.height = usize::from(list_area.height);
+
+    let shown = self.shown();
+    let top = self.top(shown.len());
+    let lines = shown
+      .iter()
+      .enumerate()
+      .skip(top)
+      .take(self.height)
+      .map(|(i, record)| self.line(record, self.current == Some(i)))
+      .collect::<Vec<_>>();
+    let block = block.title(self.title(&shown));
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), list_area);
+
+    if let Some(search_area) = search_area {
+      let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(search_area);
+      f.render_widget(Paragraph::new("/"), areas[0]);
+      match &self.error {
+        Some(e) => f.render_widget(Paragraph::new(e.clone().red()), areas[1]),
+        None => f.render_widget(self.query.widget(), areas[1]),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crossterm::event::KeyModifiers;
+  use pretty_assertions::assert_eq;
+
+  fn press(logs: &mut Logs<'_>, code: KeyCode) {
+    logs.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+  }
+
+  fn messages(logs: &Logs<'_>) -> Vec<String> {
+    logs.shown().iter().map(|r| r.message.clone()).collect()
+  }
+
+  #[test]
+  fn test_logs_new() {
+    let _ = Logs::new();
+  }
+
+  #[test]
+  fn test_logs_push() {
+    let mut logs = Logs::new();
+    logs.push(Level::Error, "line 3: unknown row \"R\"");
+    assert_eq!(logs.records.len(), 1);
+  }
+
+  #[test]
+  fn test_logs_filters() {
+    let mut logs = Logs::new();
+    logs.push(Level::Debug, "Got action: Tick");
+    logs.push(Level::Warn, "No problem loaded");
+    logs.push_job(JobId(1), "Running HiGHS");
+    logs.push_job(JobId(2), "Welcome to the CBC MILP Solver");
+    assert_eq!(messages(&logs).len(), 4);
+    press(&mut logs, KeyCode::Char('v'));
+    press(&mut logs, KeyCode::Char('v'));
+    assert_eq!(messages(&logs).len(), 3);
+    press(&mut logs, KeyCode::Char(']'));
+    assert_eq!(messages(&logs), vec!["No problem loaded"]);
+    press(&mut logs, KeyCode::Char(']'));
+    assert_eq!(messages(&logs), vec!["Running HiGHS"]);
+    press(&mut logs, KeyCode::Char('['));
+    press(&mut logs, KeyCode::Char('['));
+    assert_eq!(logs.source, None);
+  }
+
+  #[test]
+  fn test_logs_scroll_and_follow() {
+    let mut logs = Logs::new();
+    logs.height = 2;
+    for i in 0..5 {
+      logs.push(Level::Info, format!("line {i}"));
+    }
+    assert!(logs.is_following());
+    press(&mut logs, KeyCode::Up);
+    assert_eq!(logs.top, Some(2));
+    logs.push(Level::Info, "line 5");
+    assert_eq!(logs.top(logs.shown().len()), 2);
+    press(&mut logs, KeyCode::PageDown);
+    assert!(logs.is_following());
+    press(&mut logs, KeyCode::Home);
+    assert_eq!(logs.top, Some(0));
+    press(&mut logs, KeyCode::Char('f'));
+    assert!(logs.is_following());
+  }
+
+  #[test]
+  fn test_logs_search() {
+    let mut logs = Logs::new();
+    logs.height = 2;
+    for line in ["solution of -450", "cuts 4", "solution of -464", "done"] {
+      logs.push(Level::Info, line);
+    }
+    press(&mut logs, KeyCode::Char('/'));
+    assert!(logs.is_searching());
+    for c in "solution of (".chars() {
+      press(&mut logs, KeyCode::Char(c));
+    }
+    press(&mut logs, KeyCode::Enter);
+    assert!(logs.is_searching());
+    assert!(logs.error.is_some());
+    press(&mut logs, KeyCode::Backspace);
+    press(&mut logs, KeyCode::Char('-'));
+    press(&mut logs, KeyCode::Enter);
+    assert!(!logs.is_searching());
+    assert_eq!(logs.current, Some(2));
+    press(&mut logs, KeyCode::Char('N'));
+    assert_eq!(logs.current, Some(0));
+    assert_eq!(logs.top, Some(0));
+    press(&mut logs, KeyCode::Char('N'));
+    assert_eq!(logs.current, Some(0));
+    press(&mut logs, KeyCode::Char('n'));
+    assert_eq!(logs.current, Some(2));
+    let line = logs.line(logs.shown()[2], true);
+    assert_eq!(line.spans[1].content, "solution of -");
+    press(&mut logs, KeyCode::Esc);
+    assert!(logs.query.search_pattern().is_none());
+  }
+}
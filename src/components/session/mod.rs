@@ -1,62 +1,696 @@
 use super::{Component, State};
 use crate::action::mode::Mode;
 use crate::action::{scene::Scene, Action};
-use crate::router::Message;
+use crate::config::Config;
+use crate::irx_client::Connectivity;
+use crate::job::{
+  Ensemble, Job, JobId, JobSpec, JobState, Location, ParamSet, ParamSets,
+  Solver, SolverParams,
+};
+use crate::problem::{Entry, Problem};
+use crate::router::{Address, Cacheable, Kind, Message, Payload, RouterHandle};
 use crate::tui::Frame;
+use crate::utils::{expand_home, take_log_records, LogRecord};
 use color_eyre::eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use itertools::Itertools;
+use log::Level;
 use ratatui::prelude::*;
+use std::{
+  collections::HashMap,
+  fs,
+  path::{Path, PathBuf},
+};
+use strum::IntoEnumIterator;
 use tokio::sync::mpsc;
+mod command;
+mod ensemble;
 mod jobs;
 mod layers;
+mod logs;
+mod params;
 mod prompt;
 mod widgets;
+mod workspaces;
+use command::{Command, SolveArgs};
+use ensemble::EnsembleForm;
 use jobs::Jobs;
+use logs::Logs;
+use params::{Outcome, ParamsForm};
 use prompt::Prompt;
+use workspaces::Workspaces;
+
+/// The pane that receives navigation keys.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+enum Focus {
+  #[default]
+  Workspaces,
+  Jobs,
+  Logs,
+}
 
 /// Manages the session interface in a TUI application.
 ///
-/// This struct handles various components of a session, including prompts, jobs,
-/// workspaces, and other widgets. It manages their states and renders them accordingly.
+/// Lays out the workspaces, jobs, logs and prompt panes, and runs the jobs
+/// submitted from them, streaming each job's output to the Logs pane.
 #[derive(Debug)]
 pub struct Session<'a> {
   state: State,
+  router: RouterHandle,
+  message_rx_from_router: mpsc::UnboundedReceiver<Message>,
   pub message_tx_to_self: mpsc::UnboundedSender<Message>,
+  job_logs: HashMap<JobId, mpsc::UnboundedReceiver<Message>>,
+  log_records: Option<mpsc::UnboundedReceiver<LogRecord>>,
   prompt: Prompt<'a>,
   jobs: Jobs<'a>,
-  workspaces: widgets::Workspaces<'a>,
+  workspaces: Workspaces<'a>,
+  params: ParamsForm,
+  param_sets: ParamSets,
+  param_set: Option<String>,
+  ensemble: EnsembleForm,
   status: widgets::Status<'a>,
   plots: widgets::Plots<'a>,
-  logs: widgets::Logs<'a>,
+  logs: Logs<'a>,
   mode: Mode,
+  focus: Focus,
+  connectivity: Connectivity,
+  problem: Option<Problem>,
+  last_job_id: u64,
+  last_ensemble: u64,
 }
 
 impl<'a> Session<'a> {
   /// Constructs a new `Session`.
   ///
   /// Initializes the session with default components and state.
-  pub fn new() -> Session<'a> {
-    let (message_tx_to_self, _) = mpsc::unbounded_channel::<Message>();
+  ///
+  /// # Arguments
+  /// - `router`: Handle for talking to other components through the router.
+  pub fn new(router: RouterHandle) -> Session<'a> {
+    let (message_tx_to_self, message_rx_from_router) =
+      mpsc::unbounded_channel::<Message>();
     Session {
       state: State::Hidden,
+      router,
+      message_rx_from_router,
       message_tx_to_self,
+      job_logs: HashMap::new(),
+      log_records: take_log_records(),
       prompt: Prompt::new(),
       jobs: Jobs::new(),
-      workspaces: widgets::Workspaces::new(),
+      workspaces: Workspaces::new(),
+      params: ParamsForm::new(),
+      param_sets: ParamSets::default(),
+      param_set: None,
+      ensemble: EnsembleForm::new(),
       status: widgets::Status::new(),
       plots: widgets::Plots::new(),
-      logs: widgets::Logs::new(),
+      logs: Logs::new(),
       mode: Mode::default(),
+      focus: Focus::default(),
+      connectivity: Connectivity::default(),
+      problem: None,
+      last_job_id: 0,
+      last_ensemble: 0,
+    }
+  }
+
+  /// Loads an MPS file as the session's active problem.
+  ///
+  /// The file is parsed in the background, and becomes the active problem
+  /// once it's been received.
+  ///
+  /// # Arguments
+  /// - `path`: Path to the MPS file.
+  pub fn open(&self, path: &Path) {
+    let path = path.to_path_buf();
+    self.background(move || {
+      let problem = Problem::load(&path).map(Box::new);
+      Payload::ProblemLoaded(path, problem.map_err(|e| e.to_string()))
+    });
+  }
+
+  /// Makes a loaded problem the active one.
+  ///
+  /// Parse errors are reported in the Logs pane and leave any previously
+  /// loaded problem in place.
+  fn loaded(&mut self, path: &Path, problem: Result<Box<Problem>, String>) {
+    match problem {
+      Ok(problem) => {
+        let model = &problem.model;
+        self.logs.push(
+          Level::Info,
+          format!(
+            "Loaded {} ({} rows, {} columns, {} nonzeros)",
+            path.display(),
+            model.num_rows(),
+            model.num_columns(),
+            model.num_nonzeros()
+          ),
+        );
+        self.status.set_problem(&problem);
+        self.workspaces.set_active(path);
+        self.problem = Some(*problem);
+      }
+      Err(e) => {
+        self
+          .logs
+          .push(Level::Error, format!("{}: {e}", path.display()));
+      }
+    }
+  }
+
+  /// Submits the active problem to be solved.
+  ///
+  /// Jobs use the solver and parameters of the active parameter set. With
+  /// none active, local jobs use the first solver found on `PATH`, and
+  /// remote jobs are sent to the IRX API, which runs the default solver.
+  fn solve(&mut self, location: Location) {
+    self.solve_with(SolveArgs::at(location));
+  }
+
+  /// Submits the active problem to be solved with the given solver and
+  /// parameters, falling back to the active parameter set for the rest.
+  ///
+  /// Jobs only carry the name of the set when nothing was changed from it.
+  fn solve_with(&mut self, args: SolveArgs) {
+    let location = args.location;
+    if !self.can_solve(location) {
+      return;
+    }
+    let set = self
+      .param_set
+      .as_deref()
+      .and_then(|n| self.param_sets.get(n))
+      .cloned();
+    let solver = match (args.solver, &set, location) {
+      (Some(solver), _, _) => Some(solver),
+      (None, Some(set), _) => Some(set.solver),
+      (None, None, Location::Local) => Solver::detect(),
+      (None, None, Location::Remote) => Some(Solver::default()),
+    };
+    let Some(solver) = solver else {
+      let names = Solver::iter().map(Solver::executable).join(", ");
+      self
+        .logs
+        .push(Level::Error, format!("No solver found on PATH ({names})"));
+      return;
+    };
+    if location == Location::Local && solver.find().is_none() {
+      self
+        .logs
+        .push(Level::Error, format!("Not found on PATH: {solver}"));
+      return;
+    }
+    let (mut params, param_set) = match set {
+      Some(set) => (set.params, Some(set.name)),
+      None => (SolverParams::default(), None),
+    };
+    args.apply(&mut params);
+    if let Err(e) = params.validate() {
+      self.logs.push(Level::Error, format!("Can't solve: {e}"));
+      return;
+    }
+    let param_set = param_set.filter(|_| !args.overrides());
+    self.submit(location, solver, params, param_set, None);
+  }
+
+  /// Launches the jobs of an ensemble on the active problem.
+  fn launch(&mut self, ensemble: Ensemble) {
+    if !self.can_solve(ensemble.location) {
+      return;
+    }
+    if self.jobs.ensemble(&ensemble.name).next().is_some() {
+      self.logs.push(
+        Level::Warn,
+        format!("An ensemble named {} already exists", ensemble.name),
+      );
+      return;
+    }
+    if ensemble.location == Location::Local {
+      let missing = ensemble
+        .solvers
+        .iter()
+        .filter(|solver| solver.find().is_none())
+        .join(", ");
+      if !missing.is_empty() {
+        self
+          .logs
+          .push(Level::Error, format!("Not found on PATH: {missing}"));
+        return;
+      }
+    }
+    let runs = ensemble.runs();
+    self.logs.push(
+      Level::Info,
+      format!("Launching ensemble {} ({} jobs)", ensemble.name, runs.len()),
+    );
+    self.last_ensemble += 1;
+    for (solver, params) in runs {
+      self.submit(
+        ensemble.location,
+        solver,
+        params,
+        None,
+        Some(ensemble.name.clone()),
+      );
+    }
+    self.jobs.select_ensemble(&ensemble.name);
+  }
+
+  /// Checks that the active problem can be solved at a location.
+  ///
+  /// Reasons it can't are shown in the Logs pane.
+  fn can_solve(&mut self, location: Location) -> bool {
+    if self.problem.is_none() {
+      self.logs.push(Level::Warn, "No problem loaded");
+      return false;
+    }
+    if location == Location::Remote && !self.connectivity.is_online() {
+      self.logs.push(
+        Level::Warn,
+        format!("Remote solving is unavailable ({})", self.connectivity),
+      );
+      return false;
+    }
+    true
+  }
+
+  /// Queues a job for the active problem and sends it to its runner.
+  fn submit(
+    &mut self,
+    location: Location,
+    solver: Solver,
+    params: SolverParams,
+    param_set: Option<String>,
+    ensemble: Option<String>,
+  ) {
+    let Some(problem) = &self.problem else {
+      return;
+    };
+    self.last_job_id += 1;
+    let spec = JobSpec {
+      id: JobId(self.last_job_id),
+      problem: problem.path.clone(),
+      solver,
+      params,
+      param_set,
+    };
+    let mut job = Job::new(spec.clone(), problem.model.name.clone(), location);
+    job.ensemble = ensemble;
+    self.logs.push(
+      Level::Info,
+      format!("Submitted {} {} to {solver}", job.id(), job.name),
+    );
+    self.jobs.push(job);
+    let (log_tx, log_rx) = mpsc::unbounded_channel::<Message>();
+    self.router.register(Address::Job(spec.id), log_tx);
+    self.job_logs.insert(spec.id, log_rx);
+    self.send(Self::runner(location), Payload::SubmitJob(spec));
+  }
+
+  /// Cancels the selected job if it hasn't stopped yet, or the jobs of the
+  /// selected ensemble that haven't.
+  fn cancel(&mut self) {
+    if let Some(name) = self.jobs.selected_ensemble() {
+      let running = self
+        .jobs
+        .ensemble(&name)
+        .filter(|job| !job.state.is_done())
+        .map(|job| (job.location, job.id()))
+        .collect::<Vec<_>>();
+      if running.is_empty() {
+        self
+          .logs
+          .push(Level::Warn, format!("Ensemble {name} has already stopped"));
+      }
+      for (location, id) in running {
+        self.send(Self::runner(location), Payload::CancelJob(id));
+      }
+      return;
+    }
+    match self.jobs.selected().map(Job::id) {
+      Some(id) => self.cancel_job(id),
+      None => self.logs.push(Level::Warn, "No job selected"),
+    }
+  }
+
+  /// Cancels a job if it hasn't stopped yet.
+  fn cancel_job(&mut self, id: JobId) {
+    match self.jobs.get(id) {
+      Some(job) if !job.state.is_done() => {
+        let runner = Self::runner(job.location);
+        self.send(runner, Payload::CancelJob(id));
+      }
+      Some(job) => self.logs.push(
+        Level::Warn,
+        format!("{} {} is already {}", job.id(), job.name, job.state),
+      ),
+      None => self.logs.push(Level::Warn, format!("No job {id}")),
+    }
+  }
+
+  /// Writes a job and the solver output still held for it to a JSON file.
+  fn export(&mut self, id: JobId, path: &Path) {
+    let Some(job) = self.jobs.get(id) else {
+      self.logs.push(Level::Warn, format!("No job {id}"));
+      return;
+    };
+    let report = serde_json::json!({
+      "job": job,
+      "log": self.logs.output(id),
+    });
+    let written = serde_json::to_string_pretty(&report)
+      .map_err(|e| e.to_string())
+      .and_then(|json| fs::write(path, json).map_err(|e| e.to_string()));
+    match written {
+      Ok(()) => self
+        .logs
+        .push(Level::Info, format!("Exported {id} to {}", path.display())),
+      Err(e) => self
+        .logs
+        .push(Level::Error, format!("{}: {e}", path.display())),
+    }
+  }
+
+  /// Runs a line typed at the prompt, reporting mistakes in the Logs pane.
+  fn execute(&mut self, line: &str) {
+    self.logs.push(Level::Info, format!("> {line}"));
+    let command = match line.parse::<Command>() {
+      Ok(command) => command,
+      Err(e) => {
+        self.logs.push(Level::Error, e);
+        return;
+      }
+    };
+    match command {
+      Command::Open(path) => {
+        let path = self.workspaces.root().join(expand_home(&path));
+        self.open(&path);
+      }
+      Command::Solve(args) => self.solve_with(args),
+      Command::Cancel(Some(id)) => self.cancel_job(id),
+      Command::Cancel(None) => self.cancel(),
+      Command::Export(id, path) => {
+        let path = self.workspaces.root().join(expand_home(&path));
+        self.export(id, &path);
+      }
+      Command::Help => {
+        for (usage, about) in command::USAGE {
+          self.logs.push(Level::Info, format!("{usage}: {about}"));
+        }
+      }
+    }
+  }
+
+  /// Returns the address of the service that runs jobs at a location.
+  fn runner(location: Location) -> Address {
+    match location {
+      Location::Local => Address::LocalRunner,
+      Location::Remote => Address::IrxClient,
+    }
+  }
+
+  /// Sends a message to another component or service through the router.
+  fn send(&self, destination: Address, payload: Payload) {
+    Self::send_with(&self.router, destination, payload);
+  }
+
+  /// Sends a message through the given router handle.
+  fn send_with(router: &RouterHandle, destination: Address, payload: Payload) {
+    let message = Message {
+      source: Address::Session,
+      destination,
+      payload,
+      tag: None,
+      cacheable: Cacheable::No,
+      kind: Kind::Tell,
+    };
+    if let Err(e) = router.send(message) {
+      log::error!("Session failed to send message: {e}");
+    }
+  }
+
+  /// Runs blocking work, such as reading files, off the UI thread.
+  ///
+  /// The payload the work returns is sent back to the session through the
+  /// router, and applied when it's received.
+  fn background(&self, work: impl FnOnce() -> Payload + Send + 'static) {
+    let router = self.router.clone();
+    tokio::task::spawn_blocking(move || {
+      Self::send_with(&router, Address::Session, work());
+    });
+  }
+
+  /// Sets the Workspaces root and scans it for problems in the background.
+  fn set_root(&mut self, root: PathBuf) {
+    self.workspaces.set_root(root.clone());
+    self.background(move || {
+      let entries = Entry::scan(&root, workspaces::MAX_DEPTH);
+      Payload::Workspace(root, entries)
+    });
+  }
+
+  /// Applies job reports and connectivity changes received from the router.
+  fn receive(&mut self) {
+    if let Some(log_records) = self.log_records.as_mut() {
+      while let Ok((level, message)) = log_records.try_recv() {
+        self.logs.push(level, message);
+      }
+    }
+    self.receive_logs();
+    while let Ok(message) = self.message_rx_from_router.try_recv() {
+      match message.payload {
+        Payload::Connectivity(connectivity) => {
+          self.connectivity = connectivity;
+          self.jobs.set_remote_status(connectivity);
+          if !connectivity.is_online() {
+            self.logs.push(
+              Level::Warn,
+              format!("Remote solving is disabled ({connectivity})"),
+            );
+          }
+        }
+        Payload::JobState(id, state) => {
+          let mut ensemble = None;
+          if let Some(job) = self.jobs.set_state(id, state) {
+            let level = match state {
+              JobState::Failed => Level::Error,
+              JobState::Cancelled => Level::Warn,
+              _ => Level::Info,
+            };
+            let line = format!("{} {} {state}", job.id(), job.name);
+            self.logs.push(level, line);
+            ensemble = job.ensemble.clone().filter(|_| state.is_done());
+          }
+          if state.is_done() {
+            self.close_logs(id);
+          }
+          if let Some(name) = ensemble {
+            self.report_ensemble(&name);
+          }
+        }
+        Payload::JobSolution(id, solution) => {
+          self.jobs.set_solution(id, solution);
+        }
+        Payload::Workspace(root, entries) => {
+          self.workspaces.set_scan(&root, entries);
+        }
+        Payload::ProblemLoaded(path, problem) => self.loaded(&path, problem),
+        Payload::JobEvents(id, elapsed, events) => {
+          self.status.push(id, elapsed, &events);
+          self.plots.push(id, elapsed, &events);
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Sums up an ensemble in the Logs pane once all its jobs have stopped.
+  fn report_ensemble(&mut self, name: &str) {
+    let states = self
+      .jobs
+      .ensemble(name)
+      .map(|job| job.state)
+      .collect::<Vec<_>>();
+    if !states.iter().all(|state| state.is_done()) {
+      return;
+    }
+    let counts = states
+      .iter()
+      .counts()
+      .into_iter()
+      .sorted_by_key(|(state, _)| state.to_string())
+      .map(|(state, count)| format!("{count} {state}"))
+      .join(", ");
+    self
+      .logs
+      .push(Level::Info, format!("Ensemble {name} done ({counts})"));
+  }
+
+  /// Moves solver output from the jobs' log streams to the Logs pane.
+  fn receive_logs(&mut self) {
+    for log_rx in self.job_logs.values_mut() {
+      while let Ok(message) = log_rx.try_recv() {
+        if let Payload::JobLog(id, line) = message.payload {
+          self.logs.push_job(id, line);
+        }
+      }
+    }
+  }
+
+  /// Unregisters the log stream of a job that has stopped.
+  ///
+  /// Runners send a job's output before its final state, so whatever is
+  /// left in the stream is shown first.
+  fn close_logs(&mut self, id: JobId) {
+    self.receive_logs();
+    if self.job_logs.remove(&id).is_some() {
+      self.router.unregister(&Address::Job(id));
     }
   }
+
+  /// Opens the parameter form on the active set.
+  fn edit_params(&mut self) -> Option<Action> {
+    let set = self
+      .param_set
+      .as_deref()
+      .and_then(|n| self.param_sets.get(n));
+    self.params.open(set);
+    Some(Action::ChangeMode(Mode::TextInput))
+  }
+
+  /// Opens the ensemble form.
+  fn edit_ensemble(&mut self) -> Option<Action> {
+    let name = format!("ensemble-{}", self.last_ensemble + 1);
+    self.ensemble.open(name);
+    Some(Action::ChangeMode(Mode::TextInput))
+  }
+
+  /// Saves a parameter set to the workspace and makes it the active one.
+  ///
+  /// The active set is left as it was if the set can't be saved.
+  fn save_params(&mut self, set: ParamSet) {
+    let line = format!("Using parameter set {} ({})", set.name, set.solver);
+    let name = set.name.clone();
+    match self.param_sets.save(set) {
+      Ok(()) => {
+        self.logs.push(Level::Info, line);
+        self.param_set = Some(name);
+      }
+      Err(e) => self.logs.push(
+        Level::Error,
+        format!("Failed to save parameter set {name}: {e}"),
+      ),
+    }
+  }
+
+  /// Moves navigation keys on to the next pane: the Workspaces tree, the
+  /// job list, then the Logs pane.
+  fn toggle_focus(&mut self) {
+    self.focus = match self.focus {
+      Focus::Workspaces => Focus::Jobs,
+      Focus::Jobs => Focus::Logs,
+      Focus::Logs => Focus::Workspaces,
+    };
+    self.jobs.set_focused(self.focus == Focus::Jobs);
+    self.logs.set_focused(self.focus == Focus::Logs);
+  }
 }
 
 impl<'a> Component for Session<'a> {
+  /// Sets the Workspaces root from the configured problem directory.
+  ///
+  /// Falls back to the current directory when none is configured.
+  fn register_config_handler(&mut self, config: Config) -> Result<()> {
+    let root = config
+      .config
+      .problem_dir
+      .map(|dir| expand_home(&dir))
+      .unwrap_or_else(|| PathBuf::from("."));
+    match ParamSets::load(&root) {
+      Ok(sets) => self.param_sets = sets,
+      Err(e) => self.logs.push(Level::Error, format!("{e}")),
+    }
+    self.set_root(root);
+    Ok(())
+  }
+
+  /// Handles key events for navigating the Workspaces tree, job list and
+  /// Logs pane.
+  ///
+  /// Tab switches between the three panes. In the tree, arrow keys move
+  /// through and expand directories, and Enter loads the selected problem
+  /// file; in the job list, Up and Down select a job; the Logs pane handles
+  /// its own keys. While the prompt, the parameter form or the ensemble
+  /// form is open, or a search of the logs is typed, it gets all the keys.
+  fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+    if self.ensemble.is_active() {
+      if let Outcome::Submitted(ensemble) = self.ensemble.handle_key_event(key)
+      {
+        self.launch(ensemble);
+      }
+      return Ok(None);
+    }
+    if self.params.is_active() {
+      if let Outcome::Submitted(set) =
+        self.params.handle_key_event(key, &self.param_sets)
+      {
+        self.save_params(set);
+      }
+      return Ok(None);
+    }
+    if self.prompt.is_active() {
+      let root = self.workspaces.root().to_path_buf();
+      let jobs = self.jobs.ids();
+      let complete = |line: &str| command::complete(line, &root, &jobs);
+      if let Outcome::Submitted(line) =
+        self.prompt.handle_key_event(key, complete)
+      {
+        self.execute(&line);
+      }
+      return Ok(None);
+    }
+    if self.logs.is_searching() {
+      self.logs.handle_key_event(key);
+      return Ok(None);
+    }
+    if self.state == State::Hidden || self.mode != Mode::Navigation {
+      return Ok(None);
+    }
+    match (self.focus, key.code) {
+      (_, KeyCode::Tab) => self.toggle_focus(),
+      (Focus::Jobs, KeyCode::Up) => self.jobs.key_up(),
+      (Focus::Jobs, KeyCode::Down) => self.jobs.key_down(),
+      (Focus::Workspaces, KeyCode::Up) => self.workspaces.key_up(),
+      (Focus::Workspaces, KeyCode::Down) => self.workspaces.key_down(),
+      (Focus::Workspaces, KeyCode::Left) => self.workspaces.key_left(),
+      (Focus::Workspaces, KeyCode::Right) => self.workspaces.key_right(),
+      (Focus::Workspaces, KeyCode::Enter) => {
+        if let Some(path) = self.workspaces.select() {
+          self.open(&path);
+        }
+      }
+      (Focus::Logs, _) => {
+        self.logs.handle_key_event(key);
+        if self.logs.is_searching() {
+          return Ok(Some(Action::ChangeMode(Mode::TextInput)));
+        }
+      }
+      _ => {}
+    }
+    Ok(None)
+  }
+
   /// Updates the session based on the given action.
   ///
   /// Handles mode changes and view updates, managing the visibility and state of session components.
+  /// Job reports from the router are applied on every tick.
   fn update(&mut self, action: Action) -> Result<Option<Action>> {
-    if let Action::ChangeMode(mode) = action {
-      self.mode = mode;
+    match action {
+      Action::ChangeMode(mode) => self.mode = mode,
+      Action::Tick => self.receive(),
+      _ => {}
     }
     if self.mode == Mode::Navigation {
       match action {
@@ -68,13 +702,36 @@ impl<'a> Component for Session<'a> {
           if self.state == State::Visible {
             if let jobs::View::Prompt = jobs::View::from(k) {
               self.prompt.toggle();
+              if self.prompt.is_active() {
+                return Ok(Some(Action::ChangeMode(Mode::TextInput)));
+              }
             } else {
               self.jobs.set_view(k);
             }
           }
         }
+        Action::Solve if self.state == State::Visible => {
+          self.solve(Location::Local);
+        }
+        Action::SolveRemote if self.state == State::Visible => {
+          self.solve(Location::Remote);
+        }
+        Action::Cancel if self.state == State::Visible => self.cancel(),
+        Action::EditParams if self.state == State::Visible => {
+          return Ok(self.edit_params());
+        }
+        Action::EditEnsemble if self.state == State::Visible => {
+          return Ok(self.edit_ensemble());
+        }
         _ => {}
       }
+    } else if self.state == State::Visible
+      && !self.params.is_active()
+      && !self.ensemble.is_active()
+      && !self.logs.is_searching()
+      && !self.prompt.is_active()
+    {
+      return Ok(Some(Action::ChangeMode(Mode::Navigation)));
     }
     Ok(None)
   }
@@ -88,11 +745,17 @@ impl<'a> Component for Session<'a> {
     } else {
       let layers = layers::Layers::new(area);
       self.jobs.render(layers.two[0], f);
-      f.render_widget(self.workspaces.block.clone(), layers.two[1]);
+      self.workspaces.render(layers.two[1], f);
       self.prompt.render(layers.zero[2], f);
-      f.render_widget(self.status.block.clone(), layers.details_inner[0]);
-      f.render_widget(self.plots.block.clone(), layers.details_inner[1]);
-      f.render_widget(self.logs.block.clone(), layers.details_inner[2]);
+      let job = self.jobs.selected();
+      let runtime = job.and_then(|job| self.jobs.runtime(job));
+      self.status.render(layers.details_inner[0], f, job, runtime);
+      self
+        .plots
+        .render(layers.details_inner[1], f, self.jobs.selected());
+      self.logs.render(layers.details_inner[2], f);
+      self.params.render(layers.one[0], f);
+      self.ensemble.render(layers.one[0], f);
       Ok(())
     }
   }
@@ -101,9 +764,287 @@ impl<'a> Component for Session<'a> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use pretty_assertions::assert_eq;
 
   #[test]
   fn test_session_new() {
-    let _ = Session::new();
+    let (router, _) = RouterHandle::detached();
+    let _ = Session::new(router);
+  }
+
+  /// Hands the next message the session sent itself back to it.
+  async fn deliver(
+    session: &mut Session<'_>,
+    rx: &mut mpsc::UnboundedReceiver<Message>,
+  ) {
+    let message = rx.recv().await.expect("the session sends a message");
+    assert_eq!(message.destination, Address::Session);
+    session.message_tx_to_self.send(message).ok();
+    session.receive();
+  }
+
+  #[tokio::test]
+  async fn test_session_open_missing() {
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    session.open(Path::new("/nonexistent/problem.mps"));
+    deliver(&mut session, &mut rx).await;
+    assert!(session.problem.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_session_scans_and_opens_in_background() -> Result<()> {
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    let root = std::env::temp_dir()
+      .join(format!("napali-session-open-{}", std::process::id()));
+    fs::create_dir_all(&root)?;
+    let path = root.join("tiny.mps");
+    let contents = "NAME TINY\nROWS\n N COST\nCOLUMNS\n X1 COST 1\nENDATA\n";
+    fs::write(&path, contents)?;
+    session.set_root(root.clone());
+    let scan = rx.recv().await.map(|message| message.payload);
+    let Some(Payload::Workspace(scanned, entries)) = scan else {
+      panic!("the root is scanned");
+    };
+    assert_eq!(scanned, root);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].path, path);
+    session.open(&path);
+    deliver(&mut session, &mut rx).await;
+    let problem = session.problem.as_ref().map(|p| p.model.name.as_str());
+    assert_eq!(problem, Some("TINY"));
+    fs::remove_dir_all(root)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_session_solve_remote_offline() {
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    session.problem = Some(Problem {
+      path: PathBuf::from("afiro.mps"),
+      model: crate::problem::Model::default(),
+    });
+    session.connectivity = Connectivity::Disabled;
+    session.solve(Location::Remote);
+    assert!(rx.try_recv().is_err());
+    assert!(session.jobs.selected().is_none());
+  }
+
+  #[test]
+  fn test_session_job_logs() {
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    session.problem = Some(Problem {
+      path: PathBuf::from("afiro.mps"),
+      model: crate::problem::Model::default(),
+    });
+    session.connectivity = Connectivity::Online;
+    session.solve(Location::Remote);
+    let submitted = rx.try_recv().map(|message| message.destination);
+    assert_eq!(submitted.ok(), Some(Address::IrxClient));
+    assert!(session.job_logs.contains_key(&JobId(1)));
+
+    let state = Payload::JobState(JobId(1), JobState::Failed);
+    session
+      .message_tx_to_self
+      .send(Message {
+        payload: state,
+        ..Message::default()
+      })
+      .ok();
+    session.receive();
+    assert!(session.job_logs.is_empty());
+  }
+
+  #[test]
+  fn test_session_solve_with_params() -> Result<()> {
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    let root = std::env::temp_dir()
+      .join(format!("napali-session-params-{}", std::process::id()));
+    session.param_sets = ParamSets::load(&root)?;
+    session.problem = Some(Problem {
+      path: PathBuf::from("afiro.mps"),
+      model: crate::problem::Model::default(),
+    });
+    session.connectivity = Connectivity::Online;
+    let mut set = ParamSet {
+      name: String::from("quick"),
+      solver: Solver::Cbc,
+      ..ParamSet::default()
+    };
+    set.params.threads = Some(2);
+    session.save_params(set.clone());
+    assert_eq!(ParamSets::load(&root)?.get("quick"), Some(&set));
+    session.solve(Location::Remote);
+    let Ok(Payload::SubmitJob(spec)) = rx.try_recv().map(|m| m.payload) else {
+      panic!("the job is submitted");
+    };
+    assert_eq!(spec.solver, Solver::Cbc);
+    assert_eq!(spec.params, set.params);
+    assert_eq!(spec.param_set.as_deref(), Some("quick"));
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_session_save_params_fails() -> Result<()> {
+    let (router, _rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    // A file where the workspace should be, so nothing can be saved in it
+    let root = std::env::temp_dir()
+      .join(format!("napali-session-save-params-{}", std::process::id()));
+    session.param_sets = ParamSets::load(&root)?;
+    std::fs::write(&root, "")?;
+    session.save_params(ParamSet {
+      name: String::from("quick"),
+      ..ParamSet::default()
+    });
+    assert_eq!(session.param_set, None);
+    std::fs::remove_file(&root)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_session_launch_ensemble() {
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    session.problem = Some(Problem {
+      path: PathBuf::from("afiro.mps"),
+      model: crate::problem::Model::default(),
+    });
+    session.connectivity = Connectivity::Online;
+    let ensemble = Ensemble {
+      name: String::from("ensemble-1"),
+      solvers: vec![Solver::Highs, Solver::Cbc],
+      location: Location::Remote,
+      ..Ensemble::default()
+    };
+    session.launch(ensemble.clone());
+    let solvers = std::iter::from_fn(|| rx.try_recv().ok())
+      .filter_map(|message| match message.payload {
+        Payload::SubmitJob(spec) => Some(spec.solver),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(solvers, vec![Solver::Highs, Solver::Cbc]);
+    assert_eq!(session.jobs.ensemble("ensemble-1").count(), 2);
+    assert_eq!(
+      session.jobs.selected_ensemble().as_deref(),
+      Some("ensemble-1")
+    );
+
+    // A second ensemble of the same name is refused
+    session.launch(ensemble);
+    assert!(rx.try_recv().is_err());
+  }
+
+  #[test]
+  fn test_session_receive_job_state() {
+    let (router, _) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    let spec = JobSpec {
+      id: JobId(1),
+      ..JobSpec::default()
+    };
+    session
+      .jobs
+      .push(Job::new(spec, String::from("afiro"), Location::Local));
+    let state = Payload::JobState(JobId(1), JobState::Finished);
+    session
+      .message_tx_to_self
+      .send(Message {
+        payload: state,
+        ..Message::default()
+      })
+      .ok();
+    session.receive();
+    let job = session.jobs.selected();
+    assert_eq!(job.map(|job| job.state), Some(JobState::Finished));
+  }
+
+  #[test]
+  fn test_session_search_logs() -> Result<()> {
+    use crossterm::event::KeyModifiers;
+
+    let (router, _) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    session.state = State::Visible;
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    session.handle_key_events(key(KeyCode::Tab))?;
+    session.handle_key_events(key(KeyCode::Tab))?;
+    assert_eq!(session.focus, Focus::Logs);
+    let action = session.handle_key_events(key(KeyCode::Char('/')))?;
+    assert_eq!(action, Some(Action::ChangeMode(Mode::TextInput)));
+    session.update(Action::ChangeMode(Mode::TextInput))?;
+    session.handle_key_events(key(KeyCode::Char('q')))?;
+    assert!(session.logs.is_searching());
+    session.handle_key_events(key(KeyCode::Esc))?;
+    let action = session.update(Action::Tick)?;
+    assert_eq!(action, Some(Action::ChangeMode(Mode::Navigation)));
+    Ok(())
+  }
+
+  #[test]
+  fn test_session_prompt_commands() -> Result<()> {
+    use crate::action::view::View;
+    use crossterm::event::KeyModifiers;
+
+    let (router, mut rx) = RouterHandle::detached();
+    let mut session = Session::new(router);
+    session.state = State::Visible;
+    session.problem = Some(Problem {
+      path: PathBuf::from("afiro.mps"),
+      model: crate::problem::Model::default(),
+    });
+    session.connectivity = Connectivity::Online;
+    let run = |session: &mut Session<'_>, line: &str| -> Result<()> {
+      let action = session.update(Action::ChangeView(View::Prompt))?;
+      assert_eq!(action, Some(Action::ChangeMode(Mode::TextInput)));
+      session.update(Action::ChangeMode(Mode::TextInput))?;
+      for c in line.chars() {
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        session.handle_key_events(key)?;
+      }
+      let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+      session.handle_key_events(key)?;
+      let action = session.update(Action::Tick)?;
+      assert_eq!(action, Some(Action::ChangeMode(Mode::Navigation)));
+      session.update(Action::ChangeMode(Mode::Navigation))?;
+      Ok(())
+    };
+
+    run(&mut session, "solve --solver cbc --time-limit 60 --remote")?;
+    let Ok(Payload::SubmitJob(spec)) = rx.try_recv().map(|m| m.payload) else {
+      panic!("the job is submitted");
+    };
+    assert_eq!(spec.solver, Solver::Cbc);
+    assert_eq!(spec.params.time_limit, Some(60));
+    let job = session.jobs.selected();
+    assert_eq!(job.map(|job| job.location), Some(Location::Remote));
+
+    run(&mut session, "cancel #1")?;
+    let cancelled = rx.try_recv().map(|m| m.payload);
+    assert_eq!(cancelled.ok(), Some(Payload::CancelJob(JobId(1))));
+
+    let root = std::env::temp_dir()
+      .join(format!("napali-session-prompt-{}", std::process::id()));
+    fs::create_dir_all(&root)?;
+    session.workspaces.set_root(root.clone());
+    session.logs.push_job(JobId(1), "Running HiGHS");
+    run(&mut session, "export #1 job-1.json")?;
+    let report: serde_json::Value =
+      serde_json::from_str(&fs::read_to_string(root.join("job-1.json"))?)?;
+    assert_eq!(report["log"], serde_json::json!(["Running HiGHS"]));
+    assert_eq!(report["job"]["spec"]["solver"], "Cbc");
+    fs::remove_dir_all(root)?;
+
+    run(&mut session, "solve --threads 0")?;
+    run(&mut session, "frobnicate")?;
+    assert!(rx.try_recv().is_err());
+    assert!(!session.prompt.is_active());
+    Ok(())
   }
 }
@@ -0,0 +1,359 @@
+use crate::job::{ParamSet, ParamSets, Solver, SolverParams};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+  prelude::*,
+  widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+use std::str::FromStr;
+use strum::{EnumIter, IntoEnumIterator};
+
+/// A field of the parameter form, in the order they are shown.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, EnumIter)]
+enum Field {
+  #[default]
+  Name,
+  Solver,
+  TimeLimit,
+  MipGap,
+  Threads,
+  Presolve,
+}
+
+impl Field {
+  /// Returns the label shown next to the field.
+  fn label(self) -> &'static str {
+    match self {
+      Field::Name => "Name",
+      Field::Solver => "Solver",
+      Field::TimeLimit => "Time limit (s)",
+      Field::MipGap => "MIP gap",
+      Field::Threads => "Threads",
+      Field::Presolve => "Presolve",
+    }
+  }
+
+  /// Returns whether a character may be typed into the field.
+  fn accepts(self, c: char) -> bool {
+    match self {
+      Field::Name => !c.is_control(),
+      Field::TimeLimit | Field::Threads => c.is_ascii_digit(),
+      Field::MipGap => c.is_ascii_digit() || c == '.' || c == '%',
+      Field::Solver | Field::Presolve => false,
+    }
+  }
+}
+
+/// What became of a form after a key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+  /// The form is still being filled in.
+  Editing,
+  /// The form was closed without submitting it.
+  Closed,
+  /// The form was submitted with valid contents.
+  Submitted(T),
+}
+
+/// A form for choosing a solver and editing its parameters.
+///
+/// Up and Down move between fields, Left and Right cycle through the saved
+/// sets, the solvers, or presolve on and off, and typing edits the other
+/// fields. Enter validates the form and hands the set back to be saved;
+/// problems are shown at the bottom of the form until they are fixed.
+#[derive(Debug, Default)]
+pub struct ParamsForm {
+  is_active: bool,
+  field: Field,
+  name: String,
+  solver: Solver,
+  time_limit: String,
+  mip_gap: String,
+  threads: String,
+  presolve: bool,
+  error: Option<String>,
+}
+
+impl ParamsForm {
+  /// Constructs a new, inactive `ParamsForm`.
+  pub fn new() -> Self {
+    ParamsForm::default()
+  }
+
+  /// Opens the form, filled in from a set or with the default parameters.
+  pub fn open(&mut self, set: Option<&ParamSet>) {
+    let default = ParamSet {
+      name: String::from("default"),
+      solver: Solver::detect().unwrap_or_default(),
+      params: SolverParams::default(),
+    };
+    self.fill(set.unwrap_or(&default));
+    self.field = Field::default();
+    self.is_active = true;
+  }
+
+  /// Checks if the form is currently open.
+  pub fn is_active(&self) -> bool {
+    self.is_active
+  }
+
+  /// Replaces the contents of the form with a set.
+  fn fill(&mut self, set: &ParamSet) {
+    let params = &set.params;
+    let show = |value: Option<String>| value.unwrap_or_default();
+    self.name = set.name.clone();
+    self.solver = set.solver;
+    self.time_limit = show(params.time_limit.map(|t| t.to_string()));
+    self.mip_gap = show(params.mip_gap.map(|g| g.to_string()));
+    self.threads = show(params.threads.map(|t| t.to_string()));
+    self.presolve = params.presolve;
+    self.error = None;
+  }
+
+  /// Returns the text of the field being edited, if it holds text.
+  fn text_mut(&mut self) -> Option<&mut String> {
+    match self.field {
+      Field::Name => Some(&mut self.name),
+      Field::TimeLimit => Some(&mut self.time_limit),
+      Field::MipGap => Some(&mut self.mip_gap),
+      Field::Threads => Some(&mut self.threads),
+      Field::Solver | Field::Presolve => None,
+    }
+  }
+
+  /// Moves to the next field, or the previous one if `forward` is false.
+  fn step_field(&mut self, forward: bool) {
+    let fields = Field::iter().collect::<Vec<_>>();
+    let i = fields.iter().position(|&f| f == self.field).unwrap_or(0);
+    let n = fields.len();
+    self.field = fields[if forward {
+      (i + 1) % n
+    } else {
+      (i + n - 1) % n
+    }];
+  }
+
+  /// Cycles the choice in the current field.
+  fn step_choice(&mut self, forward: bool, sets: &ParamSets) {
+    match self.field {
+      Field::Name => {
+        let sets = sets.all();
+        if sets.is_empty() {
+          return;
+        }
+        let n = sets.len();
+        let i = match sets.iter().position(|s| s.name == self.name) {
+          Some(i) if forward => (i + 1) % n,
+          Some(i) => (i + n - 1) % n,
+          None if forward => 0,
+          None => n - 1,
+        };
+        self.fill(&sets[i]);
+      }
+      Field::Solver => {
+        let solvers = Solver::iter().collect::<Vec<_>>();
+        let n = solvers.len();
+        let i = solvers.iter().position(|&s| s == self.solver).unwrap_or(0);
+        self.solver = solvers[if forward {
+          (i + 1) % n
+        } else {
+          (i + n - 1) % n
+        }];
+      }
+      Field::Presolve => self.presolve = !self.presolve,
+      _ => {}
+    }
+  }
+
+  /// Handles a key press while the form is open.
+  ///
+  /// # Arguments
+  /// - `key`: The key that was pressed.
+  /// - `sets`: The saved sets, which Left and Right cycle through on the
+  ///   name field.
+  pub fn handle_key_event(
+    &mut self,
+    key: KeyEvent,
+    sets: &ParamSets,
+  ) -> Outcome<ParamSet> {
+    match key.code {
+      KeyCode::Esc => {
+        self.is_active = false;
+        return Outcome::Closed;
+      }
+      KeyCode::Enter => match self.parse() {
+        Ok(set) => {
+          self.is_active = false;
+          return Outcome::Submitted(set);
+        }
+        Err(e) => self.error = Some(e),
+      },
+      KeyCode::Up | KeyCode::BackTab => self.step_field(false),
+      KeyCode::Down | KeyCode::Tab => self.step_field(true),
+      KeyCode::Left => self.step_choice(false, sets),
+      KeyCode::Right => self.step_choice(true, sets),
+      KeyCode::Char(' ') if self.field == Field::Presolve => {
+        self.presolve = !self.presolve;
+      }
+      KeyCode::Char(c) if self.field.accepts(c) => {
+        if let Some(text) = self.text_mut() {
+          text.push(c);
+          self.error = None;
+        }
+      }
+      KeyCode::Backspace => {
+        if let Some(text) = self.text_mut() {
+          text.pop();
+          self.error = None;
+        }
+      }
+      _ => {}
+    }
+    Outcome::Editing
+  }
+
+  /// Reads the parameter set from the form.
+  ///
+  /// # Returns
+  /// The set, or a description of the first field that is invalid.
+  fn parse(&self) -> Result<ParamSet, String> {
+    fn optional<T: FromStr>(
+      text: &str,
+      what: &str,
+    ) -> Result<Option<T>, String> {
+      let text = text.trim();
+      if text.is_empty() {
+        return Ok(None);
+      }
+      text
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("{what} {text:?} is not a whole number"))
+    }
+    let mip_gap = match self.mip_gap.trim() {
+      "" => None,
+      gap => Some(gap.parse()?),
+    };
+    let set = ParamSet {
+      name: self.name.trim().to_string(),
+      solver: self.solver,
+      params: SolverParams {
+        time_limit: optional(&self.time_limit, "time limit")?,
+        mip_gap,
+        threads: optional(&self.threads, "threads")?,
+        presolve: self.presolve,
+      },
+    };
+    set.validate()?;
+    Ok(set)
+  }
+
+  /// Renders the form as a popup centered in the given area.
+  pub fn render(&self, area: Rect, f: &mut Frame<'_>) {
+    if !self.is_active {
+      return;
+    }
+    let width = area.width.min(48);
+    let height = area.height.min(Field::iter().count() as u16 + 5);
+    let popup = Rect {
+      x: area.x + (area.width - width) / 2,
+      y: area.y + (area.height - height) / 2,
+      width,
+      height,
+    };
+    let mut lines = Field::iter()
+      .map(|field| {
+        let value = match field {
+          Field::Name => self.name.clone(),
+          Field::Solver => format!("< {} >", self.solver),
+          Field::TimeLimit => self.time_limit.clone(),
+          Field::MipGap => self.mip_gap.clone(),
+          Field::Threads => self.threads.clone(),
+          Field::Presolve => {
+            String::from(if self.presolve { "< on >" } else { "< off >" })
+          }
+        };
+        let label = format!("{:<16}", field.label());
+        if field == self.field {
+          Line::from(vec![label.bold(), value.reversed()])
+        } else {
+          Line::from(vec![label.into(), value.into()])
+        }
+      })
+      .collect::<Vec<_>>();
+    lines.push(Line::default());
+    lines.push(match &self.error {
+      Some(e) => Line::from(e.clone().red()),
+      None => Line::from("Enter save · Esc close".dark_gray()),
+    });
+    let block = Block::default()
+      .title("Solver Parameters")
+      .title_alignment(Alignment::Left)
+      .borders(Borders::ALL)
+      .border_type(BorderType::Rounded)
+      .border_style(Style::default().fg(Color::LightGreen));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crossterm::event::KeyModifiers;
+  use pretty_assertions::assert_eq;
+
+  fn press(form: &mut ParamsForm, code: KeyCode) -> Outcome<ParamSet> {
+    let key = KeyEvent::new(code, KeyModifiers::NONE);
+    form.handle_key_event(key, &ParamSets::default())
+  }
+
+  fn type_text(form: &mut ParamsForm, text: &str) {
+    for c in text.chars() {
+      press(form, KeyCode::Char(c));
+    }
+  }
+
+  #[test]
+  fn test_params_form_saves_valid_set() {
+    let mut form = ParamsForm::new();
+    form.open(None);
+    assert!(form.is_active());
+    press(&mut form, KeyCode::Down);
+    form.solver = Solver::Highs;
+    press(&mut form, KeyCode::Right);
+    press(&mut form, KeyCode::Down);
+    type_text(&mut form, "60s");
+    press(&mut form, KeyCode::Down);
+    type_text(&mut form, "1%");
+    press(&mut form, KeyCode::Up);
+    press(&mut form, KeyCode::Up);
+    press(&mut form, KeyCode::Up);
+    type_text(&mut form, "s");
+    let Outcome::Submitted(set) = press(&mut form, KeyCode::Enter) else {
+      panic!("the form is valid");
+    };
+    assert!(!form.is_active());
+    assert_eq!(set.name, "defaults");
+    assert_eq!(set.solver, Solver::Cbc);
+    assert_eq!(set.params.time_limit, Some(60));
+    assert_eq!(set.params.mip_gap, "0.01".parse().ok());
+    assert_eq!(set.params.threads, None);
+  }
+
+  #[test]
+  fn test_params_form_rejects_invalid_set() {
+    let mut form = ParamsForm::new();
+    form.open(None);
+    for _ in 0..3 {
+      press(&mut form, KeyCode::Down);
+    }
+    type_text(&mut form, "150%");
+    assert_eq!(press(&mut form, KeyCode::Enter), Outcome::Editing);
+    assert!(form.is_active());
+    assert!(form.error.is_some());
+    press(&mut form, KeyCode::Backspace);
+    assert!(form.error.is_none());
+    assert_eq!(press(&mut form, KeyCode::Esc), Outcome::Closed);
+    assert!(!form.is_active());
+  }
+}
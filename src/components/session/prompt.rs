@@ -1,17 +1,32 @@
+use super::params::Outcome;
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
   prelude::*,
   widgets::{block::Block, Borders},
 };
-use tui_textarea::TextArea;
+use tui_textarea::{CursorMove, TextArea};
+
+/// The most command lines kept in the history.
+const MAX_HISTORY: usize = 100;
 
 /// Manages a prompt for text input in a TUI application.
 ///
 /// This struct handles the display and state of a text area where users can input text.
 /// It manages the active state and styling of the text area.
+/// Enter hands the typed line back to be run and closes the prompt. Up and
+/// Down step through the lines entered before, and Tab completes the word
+/// being typed, listing the choices in the title when there are several.
 #[derive(Debug)]
 pub struct Prompt<'a> {
   text: TextArea<'a>,
   is_active: bool,
+  history: Vec<String>,
+  /// Position in the history of the line being shown, if one is.
+  recalled: Option<usize>,
+  /// The line being typed before the history was stepped into.
+  draft: String,
+  /// Completions of the word being typed, when there are several.
+  choices: Vec<String>,
 }
 
 impl<'a> Prompt<'a> {
@@ -20,6 +35,10 @@ impl<'a> Prompt<'a> {
     Prompt {
       text: TextArea::default(),
       is_active: false,
+      history: Vec::new(),
+      recalled: None,
+      draft: String::new(),
+      choices: Vec::new(),
     }
   }
 
@@ -52,8 +71,14 @@ impl<'a> Prompt<'a> {
     if self.is_active {
       self.text.set_cursor_line_style(Style::default());
       self.text.set_placeholder_text(">");
+      let title = if self.choices.is_empty() {
+        String::from("Enter run · Tab complete · ↑↓ history · Esc close")
+      } else {
+        self.choices.join(" ")
+      };
       self.text.set_block(
         Block::default()
+          .title(title)
           .borders(Borders::ALL)
           .style(Style::default().fg(Color::LightGreen)),
       );
@@ -76,6 +101,124 @@ impl<'a> Prompt<'a> {
     self.is_active = !self.is_active;
   }
 
+  /// Checks if the prompt is currently active.
+  pub fn is_active(&self) -> bool {
+    self.is_active
+  }
+
+  /// Returns the line typed so far.
+  fn line(&self) -> &str {
+    self.text.lines()[0].as_str()
+  }
+
+  /// Replaces the line typed so far, with the cursor at its end.
+  fn set_line(&mut self, line: String) {
+    self.text = TextArea::new(vec![line]);
+    self.text.move_cursor(CursorMove::End);
+  }
+
+  /// Shows an older line from the history, or a newer one if `older` is
+  /// false, coming back to the line being typed past the newest.
+  fn recall(&mut self, older: bool) {
+    let recalled = match (self.recalled, older) {
+      (None, true) if !self.history.is_empty() => {
+        self.draft = self.line().to_string();
+        Some(self.history.len() - 1)
+      }
+      (Some(i), true) => Some(i.saturating_sub(1)),
+      (Some(i), false) if i + 1 < self.history.len() => Some(i + 1),
+      (Some(_), false) => None,
+      (None, _) => return,
+    };
+    self.recalled = recalled;
+    let line = match recalled {
+      Some(i) => self.history[i].clone(),
+      None => std::mem::take(&mut self.draft),
+    };
+    self.set_line(line);
+  }
+
+  /// Completes the word being typed as far as all its completions agree.
+  fn complete(&mut self, complete: impl Fn(&str) -> (usize, Vec<String>)) {
+    let (start, choices) = complete(self.line());
+    let Some(first) = choices.first() else {
+      return;
+    };
+    let common = choices.iter().fold(first.as_str(), |common, choice| {
+      let len = common
+        .char_indices()
+        .zip(choice.chars())
+        .take_while(|((_, a), b)| a == b)
+        .last()
+        .map_or(0, |((i, a), _)| i + a.len_utf8());
+      &common[..len]
+    });
+    let mut line = format!("{}{common}", &self.line()[..start]);
+    if choices.len() == 1 && !common.ends_with('/') {
+      line.push(' ');
+    }
+    self.set_line(line);
+    self.choices = if choices.len() > 1 {
+      choices
+    } else {
+      Vec::new()
+    };
+  }
+
+  /// Handles a key press while the prompt is open.
+  ///
+  /// # Arguments
+  /// - `key`: The key that was pressed.
+  /// - `complete`: Finds the completions of the last word of a line, and
+  ///   where that word starts.
+  ///
+  /// # Returns
+  /// The line entered, once Enter is pressed on one.
+  pub fn handle_key_event(
+    &mut self,
+    key: KeyEvent,
+    complete: impl Fn(&str) -> (usize, Vec<String>),
+  ) -> Outcome<String> {
+    match key.code {
+      KeyCode::Esc => {
+        self.close();
+        return Outcome::Closed;
+      }
+      KeyCode::Enter => {
+        let line = self.line().trim().to_string();
+        self.close();
+        if line.is_empty() {
+          return Outcome::Closed;
+        }
+        if self.history.last() != Some(&line) {
+          self.history.push(line.clone());
+        }
+        if self.history.len() > MAX_HISTORY {
+          self.history.remove(0);
+        }
+        return Outcome::Submitted(line);
+      }
+      KeyCode::Up => self.recall(true),
+      KeyCode::Down => self.recall(false),
+      KeyCode::Tab => self.complete(complete),
+      _ => {
+        if self.text.input(key) {
+          self.recalled = None;
+          self.choices.clear();
+        }
+      }
+    }
+    Outcome::Editing
+  }
+
+  /// Closes the prompt and clears the line.
+  fn close(&mut self) {
+    self.is_active = false;
+    self.recalled = None;
+    self.choices.clear();
+    self.set_line(String::new());
+  }
+
   /// Renders the prompt onto the specified area of the frame.
   ///
   /// # Arguments
@@ -91,6 +234,8 @@ impl<'a> Prompt<'a> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crossterm::event::KeyModifiers;
+  use pretty_assertions::assert_eq;
 
   #[test]
   fn test_prompt_new() {
@@ -103,4 +248,66 @@ mod tests {
     prompt.toggle();
     prompt.toggle();
   }
+
+  fn press(prompt: &mut Prompt<'_>, code: KeyCode) -> Outcome<String> {
+    let key = KeyEvent::new(code, KeyModifiers::NONE);
+    prompt.handle_key_event(key, |line| {
+      let start = line.rfind(' ').map_or(0, |i| i + 1);
+      let choices = ["solve", "sort", "open"]
+        .iter()
+        .filter(|choice| choice.starts_with(&line[start..]))
+        .map(|choice| choice.to_string())
+        .collect();
+      (start, choices)
+    })
+  }
+
+  fn type_text(prompt: &mut Prompt<'_>, text: &str) {
+    for c in text.chars() {
+      press(prompt, KeyCode::Char(c));
+    }
+  }
+
+  #[test]
+  fn test_prompt_history() {
+    let mut prompt = Prompt::new();
+    prompt.toggle();
+    type_text(&mut prompt, "open a.mps");
+    let line = press(&mut prompt, KeyCode::Enter);
+    assert_eq!(line, Outcome::Submitted(String::from("open a.mps")));
+    assert!(!prompt.is_active());
+    prompt.toggle();
+    type_text(&mut prompt, "help");
+    press(&mut prompt, KeyCode::Enter);
+    prompt.toggle();
+    type_text(&mut prompt, "so");
+    press(&mut prompt, KeyCode::Up);
+    assert_eq!(prompt.line(), "help");
+    press(&mut prompt, KeyCode::Up);
+    press(&mut prompt, KeyCode::Up);
+    assert_eq!(prompt.line(), "open a.mps");
+    press(&mut prompt, KeyCode::Down);
+    press(&mut prompt, KeyCode::Down);
+    assert_eq!(prompt.line(), "so");
+    assert_eq!(press(&mut prompt, KeyCode::Esc), Outcome::Closed);
+    assert_eq!(prompt.history.len(), 2);
+  }
+
+  #[test]
+  fn test_prompt_complete() {
+    let mut prompt = Prompt::new();
+    prompt.toggle();
+    type_text(&mut prompt, "s");
+    press(&mut prompt, KeyCode::Tab);
+    assert_eq!(prompt.line(), "so");
+    assert_eq!(prompt.choices, ["solve", "sort"]);
+    type_text(&mut prompt, "l");
+    assert!(prompt.choices.is_empty());
+    press(&mut prompt, KeyCode::Tab);
+    assert_eq!(prompt.line(), "solve ");
+    assert_eq!(
+      press(&mut prompt, KeyCode::Enter),
+      Outcome::Submitted("solve".into())
+    );
+  }
 }
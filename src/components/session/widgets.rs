@@ -1,36 +1,34 @@
+use crate::job::{Job, JobId, Progress, SolveStatus, SolverEvent};
+use crate::problem::Problem;
+use itertools::Itertools;
+use itertools::MinMaxResult::{MinMax, NoElements, OneElement};
 use ratatui::{
   prelude::*,
-  widgets::{block::Block, BorderType, Borders},
+  widgets::{
+    block::Block, Axis, BorderType, Borders, Chart, Dataset, Paragraph,
+  },
 };
+use std::{collections::HashMap, time::Duration};
 
-/// Represents the Workspaces section in a TUI application.
-///
-/// This struct manages the display of workspace-related information, encapsulating a `Block` widget.
-#[derive(Debug)]
-pub struct Workspaces<'a> {
-  pub block: Block<'a>,
-}
-
-impl<'a> Workspaces<'a> {
-  /// Constructs a new `Workspaces` instance with default settings.
-  pub fn new() -> Workspaces<'a> {
-    Workspaces {
-      block: Block::default()
-        .title("Workspaces")
-        .title_alignment(Alignment::Left)
-        .borders(Borders::ALL)
-        .border_style(Style::default())
-        .border_type(BorderType::Rounded),
-    }
-  }
+/// What the solver of a job has reported so far.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct JobStats {
+  progress: Progress,
+  nodes: Option<u64>,
+  cuts: Option<u64>,
+  status: Option<SolveStatus>,
 }
 
 /// Represents the Status section in a TUI application.
 ///
-/// This struct is responsible for displaying the current status, using a `Block` widget for visualization.
+/// This struct shows the size of the active problem and, below it, how the
+/// selected job is getting on: its status, objective, runtime, and the
+/// nodes, cuts and gap its solver reported.
 #[derive(Debug)]
 pub struct Status<'a> {
   pub block: Block<'a>,
+  lines: Vec<Line<'a>>,
+  stats: HashMap<JobId, JobStats>,
 }
 
 impl<'a> Status<'a> {
@@ -43,52 +41,280 @@ impl<'a> Status<'a> {
         .borders(Borders::ALL)
         .border_style(Style::default())
         .border_type(BorderType::Rounded),
+      lines: vec![Line::from("No problem loaded".dark_gray())],
+      stats: HashMap::new(),
+    }
+  }
+
+  /// Shows the size of the given problem.
+  pub fn set_problem(&mut self, problem: &Problem) {
+    let model = &problem.model;
+    self.lines = vec![
+      Line::from(vec![
+        model.name.clone().bold(),
+        format!(" ({}, {})", model.kind(), model.sense).into(),
+      ]),
+      Line::from(format!(
+        "Vars: {} Const: {} Nonzeros: {} Ints: {}",
+        thousands(model.num_columns() as u64),
+        thousands(model.num_rows() as u64),
+        thousands(model.num_nonzeros() as u64),
+        thousands(model.num_integers() as u64)
+      )),
+    ];
+  }
+
+  /// Records what the solver of a job reported.
+  pub fn push(&mut self, id: JobId, elapsed: Duration, events: &[SolverEvent]) {
+    let stats = self.stats.entry(id).or_default();
+    for event in events {
+      if let Some(progress) = stats.progress.after(elapsed, event) {
+        stats.progress = progress;
+      }
+      match event {
+        SolverEvent::Nodes { explored, .. } => stats.nodes = Some(*explored),
+        SolverEvent::Cuts(cuts) => stats.cuts = Some(*cuts),
+        SolverEvent::Status(status) => {
+          stats.status = Some(SolveStatus::from(status.as_str()));
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Builds the lines describing a job.
+  fn job_lines(&self, job: &Job, runtime: Option<Duration>) -> Vec<Line<'a>> {
+    let stats = self.stats.get(&job.spec.id).cloned().unwrap_or_default();
+    let solution = job.solution.as_ref();
+    let status = stats
+      .status
+      .or_else(|| Some(SolveStatus::from(solution?.status.as_str())));
+    let status = match status {
+      Some(status @ SolveStatus::Optimal) => status.to_string().green(),
+      Some(status @ (SolveStatus::Infeasible | SolveStatus::Unbounded)) => {
+        status.to_string().red()
+      }
+      Some(status) => status.to_string().yellow(),
+      None => job.state.to_string().dark_gray(),
+    };
+    let objective = solution
+      .and_then(|s| s.objective)
+      .or(stats.progress.primal)
+      .map_or(String::from("NA"), |o| o.to_string());
+    let or_na = |value: Option<String>| value.unwrap_or(String::from("NA"));
+    vec![
+      Line::from(
+        format!("{} {} ({})", job.id(), job.name, job.spec.solver).bold(),
+      ),
+      Line::from(vec![
+        Span::from("Status: "),
+        status,
+        Span::from(format!(" Obj: {objective}")),
+      ]),
+      Line::from(format!(
+        "Time: {} Cuts: {} Nodes: {} Gap: {}",
+        or_na(runtime.map(duration)),
+        or_na(stats.cuts.map(thousands)),
+        or_na(stats.nodes.map(thousands)),
+        or_na(stats.progress.gap().map(|g| format!("{:.2}%", g * 100.0)))
+      )),
+    ]
+  }
+
+  /// Renders the status in the specified area of the frame.
+  ///
+  /// # Arguments
+  /// - `area`: The area to render the status in.
+  /// - `f`: The frame to render onto.
+  /// - `job`: The selected job, if any.
+  /// - `runtime`: How long the selected job has been running.
+  pub fn render(
+    &mut self,
+    area: Rect,
+    f: &mut Frame<'_>,
+    job: Option<&Job>,
+    runtime: Option<Duration>,
+  ) {
+    let mut lines = self.lines.clone();
+    match job {
+      Some(job) => lines.extend(self.job_lines(job, runtime)),
+      None => lines.push(Line::from("No job selected".dark_gray())),
     }
+    f.render_widget(Paragraph::new(lines).block(self.block.clone()), area);
+  }
+}
+
+/// Formats a count with thousands separators, e.g. `4,832`.
+fn thousands(n: u64) -> String {
+  let digits = n.to_string().into_bytes();
+  digits
+    .rchunks(3)
+    .rev()
+    .map(|group| String::from_utf8_lossy(group))
+    .join(",")
+}
+
+/// Formats a runtime to the most useful precision, e.g. `0.6s` or `10m 5s`.
+fn duration(t: Duration) -> String {
+  let secs = t.as_secs();
+  match secs {
+    0..=59 => format!("{:.1}s", t.as_secs_f64()),
+    60..=3599 => format!("{}m {}s", secs / 60, secs % 60),
+    _ => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
   }
 }
 
 /// Represents the Plots section in a TUI application.
 ///
-/// This struct manages the display of plot-related data, encapsulating a `Block` widget.
+/// This struct charts the progress of the selected job over wall time: the
+/// primal and dual bounds above, and the MIP gap between them below.
 #[derive(Debug)]
 pub struct Plots<'a> {
   pub block: Block<'a>,
+  series: HashMap<JobId, Vec<Progress>>,
 }
 
 impl<'a> Plots<'a> {
   /// Constructs a new `Plots` instance with default settings.
   pub fn new() -> Plots<'a> {
     Plots {
+      // Titled when rendered, after the selected job
       block: Block::default()
-        .title("Plot A")
         .title_alignment(Alignment::Left)
         .borders(Borders::ALL)
         .border_style(Style::default())
         .border_type(BorderType::Rounded),
+      series: HashMap::new(),
     }
   }
-}
 
-/// Represents the Logs section in a TUI application.
-///
-/// This struct is used for displaying logs, using a `Block` widget for the UI.
-#[derive(Debug)]
-pub struct Logs<'a> {
-  pub block: Block<'a>,
-}
-
-impl<'a> Logs<'a> {
-  /// Constructs a new `Logs` instance with default settings.
-  pub fn new() -> Logs<'a> {
-    Logs {
-      block: Block::default()
-        .title("Logs")
-        .title_alignment(Alignment::Left)
-        .borders(Borders::ALL)
-        .border_style(Style::default())
-        .border_type(BorderType::Rounded),
+  /// Records the bounds a job reported.
+  ///
+  /// # Arguments
+  /// - `id`: The job.
+  /// - `elapsed`: Wall time since the job started, when it reported them.
+  /// - `events`: The events the job reported, of which only bounds are
+  ///   plotted.
+  pub fn push(&mut self, id: JobId, elapsed: Duration, events: &[SolverEvent]) {
+    let series = self.series.entry(id).or_default();
+    for event in events {
+      let last = series.last().copied().unwrap_or_default();
+      series.extend(last.after(elapsed, event));
     }
   }
+
+  /// Creates a chart of the given datasets, with time on the x axis.
+  fn chart<'d>(
+    title: &'d str,
+    datasets: Vec<Dataset<'d>>,
+    x_max: f64,
+    y_bounds: [f64; 2],
+  ) -> Chart<'d> {
+    let [y_min, y_max] = y_bounds;
+    Chart::new(datasets)
+      .block(Block::default().title(title.dark_gray()))
+      .x_axis(
+        Axis::default()
+          .style(Style::default().fg(Color::Gray))
+          .labels(vec![Span::from("0s"), Span::from(format!("{x_max:.0}s"))])
+          .bounds([0.0, x_max]),
+      )
+      .y_axis(
+        Axis::default()
+          .style(Style::default().fg(Color::Gray))
+          .labels(vec![
+            Span::from(format!("{y_min:.6}")),
+            Span::from(format!("{y_max:.6}")),
+          ])
+          .bounds(y_bounds),
+      )
+  }
+
+  /// Returns y axis bounds that fit the given values, with a little room.
+  fn y_bounds(values: impl Iterator<Item = f64>) -> [f64; 2] {
+    let (min, max) = match values.minmax() {
+      NoElements => (0.0, 0.0),
+      OneElement(v) => (v, v),
+      MinMax(min, max) => (min, max),
+    };
+    // A flat line is centered, rather than drawn along the axis
+    let pad = if max > min {
+      (max - min) * 0.05
+    } else {
+      (max.abs() * 0.01).max(1.0)
+    };
+    [min - pad, max + pad]
+  }
+
+  /// Renders the progress of a job in the specified area of the frame.
+  ///
+  /// # Arguments
+  /// - `area`: The area to render the plots in.
+  /// - `f`: The frame to render onto.
+  /// - `job`: The selected job, if any.
+  pub fn render(&self, area: Rect, f: &mut Frame<'_>, job: Option<&Job>) {
+    let series = job.and_then(|job| self.series.get(&job.spec.id));
+    let title = match job {
+      Some(job) => {
+        format!("Progress · {} {} ({})", job.id(), job.name, job.spec.solver)
+      }
+      None => String::from("Progress"),
+    };
+    let block = self.block.clone().title(title);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let Some(series) = series.filter(|series| !series.is_empty()) else {
+      let text = if job.is_some() {
+        "No progress reported yet"
+      } else {
+        "Select a job to plot its progress"
+      };
+      f.render_widget(Paragraph::new(text.dark_gray()), inner);
+      return;
+    };
+
+    let secs = |p: &Progress| p.elapsed.as_secs_f64();
+    let points = |bound: fn(&Progress) -> Option<f64>| {
+      series
+        .iter()
+        .filter_map(|p| Some((secs(p), bound(p)?)))
+        .collect::<Vec<_>>()
+    };
+    let primal = points(|p| Some(p.primal?.0));
+    let dual = points(|p| Some(p.dual?.0));
+    let gap = points(|p| Some(p.gap()? * 100.0));
+    let x_max = series.iter().map(secs).fold(1.0, f64::max);
+
+    let areas = Layout::default()
+      .direction(Direction::Vertical)
+      .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+      .split(inner);
+    let bounds = Self::y_bounds(primal.iter().chain(&dual).map(|&(_, y)| y));
+    let datasets = vec![
+      Dataset::default()
+        .name("primal")
+        .marker(symbols::Marker::Braille)
+        .style(Style::default().fg(Color::Green))
+        .data(&primal),
+      Dataset::default()
+        .name("dual")
+        .marker(symbols::Marker::Braille)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&dual),
+    ];
+    f.render_widget(Self::chart("Bounds", datasets, x_max, bounds), areas[0]);
+    let max_gap = gap.iter().map(|&(_, y)| y).fold(0.0, f64::max);
+    let datasets = vec![Dataset::default()
+      .name("gap %")
+      .marker(symbols::Marker::Braille)
+      .style(Style::default().fg(Color::Yellow))
+      .data(&gap)];
+    let gap_bounds = [0.0, max_gap.max(1.0)];
+    f.render_widget(
+      Self::chart("MIP gap (%)", datasets, x_max, gap_bounds),
+      areas[1],
+    );
+  }
 }
 
 #[cfg(test)]
@@ -96,13 +322,45 @@ mod tests {
   use super::*;
 
   #[test]
-  fn test_workspaces_new() {
-    let _ = Workspaces::new();
+  fn test_status_new() {
+    let _ = Status::new();
   }
 
   #[test]
-  fn test_status_new() {
-    let _ = Status::new();
+  fn test_status_push() {
+    use crate::job::Objective;
+
+    let mut status = Status::new();
+    let id = JobId(1);
+    let second = Duration::from_secs(1);
+    let events = [
+      SolverEvent::Bound(Objective(-470.0)),
+      SolverEvent::Incumbent(Objective(-464.0)),
+      SolverEvent::Cuts(4),
+      SolverEvent::Nodes {
+        explored: 4832,
+        open: 12,
+      },
+    ];
+    status.push(id, second, &events);
+    status.push(id, 2 * second, &[SolverEvent::Status("Optimal".into())]);
+    let stats = &status.stats[&id];
+    assert_eq!(stats.progress.primal, Some(Objective(-464.0)));
+    assert_eq!(stats.progress.elapsed, second);
+    assert_eq!(stats.nodes, Some(4832));
+    assert_eq!(stats.cuts, Some(4));
+    assert_eq!(stats.status, Some(SolveStatus::Optimal));
+  }
+
+  #[test]
+  fn test_status_formats() {
+    assert_eq!(thousands(0), "0");
+    assert_eq!(thousands(999), "999");
+    assert_eq!(thousands(4832), "4,832");
+    assert_eq!(thousands(1234567), "1,234,567");
+    assert_eq!(duration(Duration::from_millis(600)), "0.6s");
+    assert_eq!(duration(Duration::from_secs(605)), "10m 5s");
+    assert_eq!(duration(Duration::from_secs(3720)), "1h 2m");
   }
 
   #[test]
@@ -111,7 +369,26 @@ mod tests {
   }
 
   #[test]
-  fn test_logs_new() {
-    let _ = Logs::new();
+  fn test_plots_push() {
+    use crate::job::Objective;
+
+    let mut plots = Plots::new();
+    let id = JobId(1);
+    let second = Duration::from_secs(1);
+    plots.push(id, second, &[SolverEvent::Bound(Objective(-470.0))]);
+    let events = [
+      SolverEvent::Nodes {
+        explored: 10,
+        open: 2,
+      },
+      SolverEvent::Incumbent(Objective(-464.0)),
+    ];
+    plots.push(id, 2 * second, &events);
+    let series = &plots.series[&id];
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[1].elapsed, 2 * second);
+    assert_eq!(series[1].dual, Some(Objective(-470.0)));
+    assert!(series[1].gap().is_some());
+    assert_eq!(Plots::y_bounds([5.0].into_iter()), [4.0, 6.0]);
   }
 }
@@ -0,0 +1,218 @@
+use crate::problem::Entry;
+use ratatui::{
+  prelude::*,
+  widgets::{
+    block::{Block, Title},
+    BorderType, Borders,
+  },
+};
+use std::path::{Path, PathBuf};
+use tui_tree_widget::{Tree, TreeItem, TreeState};
+
+/// How many directory levels below the root are scanned for problems.
+pub const MAX_DEPTH: usize = 4;
+
+/// Builds the tree item displayed for an entry.
+fn item<'a>(entry: &Entry, active: Option<&PathBuf>) -> TreeItem<'a, String> {
+  let id = entry.path.to_string_lossy().to_string();
+  match &entry.children {
+    Some(children) => {
+      let children = children.iter().map(|c| item(c, active)).collect();
+      TreeItem::new(id.clone(), format!("{}/", entry.name), children)
+        .unwrap_or_else(|_| TreeItem::new_leaf(id, entry.name.clone()))
+    }
+    None => {
+      let marker = if active == Some(&entry.path) {
+        "▶ "
+      } else {
+        ""
+      };
+      // Without integer markers near the start, it's likely an LP
+      let kind = entry.kind.map_or(String::from("LP?"), |k| k.to_string());
+      let line = Line::from(vec![
+        format!("{marker}{} ", entry.name).into(),
+        format!("({kind}) {}", format_size(entry.size)).dark_gray(),
+      ]);
+      TreeItem::new_leaf(id, line)
+    }
+  }
+}
+
+/// Formats a byte count using binary units.
+fn format_size(bytes: u64) -> String {
+  const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+  let mut size = bytes as f64;
+  let mut unit = 0;
+  while size >= 1024.0 && unit < UNITS.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+  if unit == 0 {
+    format!("{bytes} {}", UNITS[0])
+  } else {
+    format!("{size:.1} {}", UNITS[unit])
+  }
+}
+
+/// Represents the Workspaces section in a TUI application.
+///
+/// Browses the problem files under a root directory as a tree. Directories
+/// are expanded and collapsed in place; selecting a file yields its path so
+/// it can be loaded as the active problem.
+#[derive(Debug)]
+pub struct Workspaces<'a> {
+  block: Block<'a>,
+  root: PathBuf,
+  entries: Vec<Entry>,
+  state: TreeState<String>,
+  active: Option<PathBuf>,
+}
+
+impl<'a> Workspaces<'a> {
+  /// Constructs a new `Workspaces` instance with default settings.
+  pub fn new() -> Workspaces<'a> {
+    Workspaces {
+      block: Block::default()
+        .title("Workspaces")
+        .title_alignment(Alignment::Left)
+        .borders(Borders::ALL)
+        .border_style(Style::default())
+        .border_type(BorderType::Rounded),
+      root: PathBuf::new(),
+      entries: Vec::new(),
+      state: TreeState::default(),
+      active: None,
+    }
+  }
+
+  /// Sets the root directory, emptying the tree until it's been scanned.
+  pub fn set_root(&mut self, root: PathBuf) {
+    self.root = root;
+    self.set_entries(Vec::new());
+  }
+
+  /// Returns the root directory.
+  pub fn root(&self) -> &Path {
+    &self.root
+  }
+
+  /// Shows the problem files found under a root directory.
+  ///
+  /// Scans of a root that has since been replaced are ignored.
+  pub fn set_scan(&mut self, root: &Path, entries: Vec<Entry>) {
+    if root == self.root {
+      self.set_entries(entries);
+    }
+  }
+
+  /// Replaces the tree, selecting its first entry.
+  fn set_entries(&mut self, entries: Vec<Entry>) {
+    self.entries = entries;
+    self.state = TreeState::default();
+    let items = self.items();
+    self.state.select_first(&items);
+  }
+
+  /// Marks the given path as the active problem.
+  pub fn set_active(&mut self, path: &Path) {
+    self.active = Some(path.to_path_buf());
+  }
+
+  /// Builds the tree items for all scanned entries.
+  fn items(&self) -> Vec<TreeItem<'a, String>> {
+    self
+      .entries
+      .iter()
+      .map(|entry| item(entry, self.active.as_ref()))
+      .collect()
+  }
+
+  /// Moves the selection up.
+  pub fn key_up(&mut self) {
+    let items = self.items();
+    self.state.key_up(&items);
+  }
+
+  /// Moves the selection down.
+  pub fn key_down(&mut self) {
+    let items = self.items();
+    self.state.key_down(&items);
+  }
+
+  /// Collapses the selected directory, or moves to its parent.
+  pub fn key_left(&mut self) {
+    self.state.key_left();
+  }
+
+  /// Expands the selected directory.
+  pub fn key_right(&mut self) {
+    self.state.key_right();
+  }
+
+  /// Activates the selected entry.
+  ///
+  /// # Returns
+  /// The path of the selected problem file, or `None` if a directory was
+  /// selected, in which case it is expanded or collapsed instead.
+  pub fn select(&mut self) -> Option<PathBuf> {
+    let path = self.state.selected().last().map(PathBuf::from)?;
+    if path.is_dir() {
+      self.state.toggle_selected();
+      None
+    } else {
+      Some(path)
+    }
+  }
+
+  /// Renders the problem tree in the specified area of the frame.
+  pub fn render(&mut self, area: Rect, f: &mut Frame<'_>) {
+    let block = self.block.clone().title(
+      Title::from(format!(" {} ", self.root.display()).dark_gray())
+        .alignment(Alignment::Right),
+    );
+    match Tree::new(self.items()) {
+      Ok(tree) => {
+        let tree = tree
+          .block(block)
+          .highlight_style(Style::default().bold().reversed());
+        f.render_stateful_widget(tree, area, &mut self.state);
+      }
+      Err(_) => f.render_widget(block, area),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_workspaces_new() {
+    let _ = Workspaces::new();
+  }
+
+  #[test]
+  fn test_format_size() {
+    assert_eq!(format_size(512), "512 B");
+    assert_eq!(format_size(1536), "1.5 KiB");
+    assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+  }
+
+  #[test]
+  fn test_workspaces_set_scan() {
+    let mut workspaces = Workspaces::new();
+    workspaces.set_root(PathBuf::from("miplib"));
+    let entry = Entry {
+      path: PathBuf::from("miplib/a.mps"),
+      name: String::from("a.mps"),
+      kind: None,
+      size: 0,
+      children: None,
+    };
+    workspaces.set_scan(Path::new("other"), vec![entry.clone()]);
+    assert!(workspaces.entries.is_empty());
+    workspaces.set_scan(Path::new("miplib"), vec![entry]);
+    assert_eq!(workspaces.entries.len(), 1);
+  }
+}
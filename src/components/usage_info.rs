@@ -102,12 +102,27 @@ impl Component for UsageInfo {
         Row::new(vec!["S", "Session"]),
         Row::new(vec!["I", "Configuration info"]),
         Row::new(vec!["E", "Email prompt"]),
+        Row::new(vec!["↑↓←→", "Browse workspaces"]),
+        Row::new(vec!["Enter", "Open problem"]),
+        Row::new(vec!["Tab", "Switch workspaces/jobs/logs"]),
+        Row::new(vec!["/ n N", "Search logs, next/previous match"]),
+        Row::new(vec!["v [ ]", "Log level, log source"]),
+        Row::new(vec!["f End", "Follow log output"]),
+        Row::new(vec![".", "Command prompt, help lists commands"]),
+        Row::new(vec!["Ctrl-R", "Solve active problem"]),
+        Row::new(vec!["Alt-R", "Solve active problem remotely"]),
+        Row::new(vec!["Ctrl-X", "Cancel selected job"]),
+        Row::new(vec!["Ctrl-P", "Edit solver parameters"]),
+        Row::new(vec!["Ctrl-N", "Configure ensemble run"]),
+        Row::new(vec!["Ctrl-G", "Regenerate API key (Internals)"]),
+        Row::new(vec!["Ctrl-K", "Revoke API key (Internals)"]),
+        Row::new(vec!["Ctrl-E", "Show/hide API key (Internals)"]),
         Row::new(vec!["Q", "Quit"]),
         Row::new(vec!["?", "Show usage help"]),
       ];
       let table = Table::new(
         rows,
-        [Constraint::Percentage(10), Constraint::Percentage(90)],
+        [Constraint::Percentage(20), Constraint::Percentage(80)],
       )
       .header(
         Row::new(vec!["Key", "Action"])
@@ -4,7 +4,10 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use derive_deref::{Deref, DerefMut};
 use ratatui::style::{Color, Modifier, Style};
 use serde::{de::Deserializer, Deserialize, Serialize};
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::PathBuf,
+};
 
 const CONFIG: &str = "
 {
@@ -31,6 +34,11 @@ const CONFIG: &str = "
       \"<L>\": \"ChangeView(L)\",
       \"<R>\": \"ChangeView(R)\",
       \"<.>\": \"ChangeView(Prompt)\",
+      \"<Ctrl-r>\": \"Solve\",
+      \"<Alt-r>\": \"SolveRemote\",
+      \"<Ctrl-x>\": \"Cancel\",
+      \"<Ctrl-p>\": \"EditParams\",
+      \"<Ctrl-n>\": \"EditEnsemble\",
       \"<?>\": \"ToggleOverlay(UsageInfo)\",
       \"<q>\": \"Quit\",
       \"<Ctrl-d>\": \"Quit\",
@@ -44,6 +52,9 @@ const CONFIG: &str = "
       \"<A>\": \"ChangeView(A)\",
       \"<L>\": \"ChangeView(L)\",
       \"<R>\": \"ChangeView(R)\",
+      \"<Ctrl-g>\": \"RegenerateKey\",
+      \"<Ctrl-k>\": \"RevokeKey\",
+      \"<Ctrl-e>\": \"RevealKey\",
       \"<?>\": \"ToggleOverlay(UsageInfo)\",
       \"<q>\": \"Quit\",
       \"<Ctrl-d>\": \"Quit\",
@@ -53,9 +64,74 @@ const CONFIG: &str = "
   }
 }";
 
+/// Configures how the IRX client reaches the API.
+///
+/// Values given on the command line take precedence over these.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct IrxConfig {
+  /// Base URL of the API, e.g. a staging deployment.
+  #[serde(default)]
+  pub endpoint: Option<String>,
+  /// File holding the API keys.
+  #[serde(default)]
+  pub key_file: Option<PathBuf>,
+  /// Never contact the API, disabling remote features.
+  #[serde(default)]
+  pub offline: bool,
+  /// Profile whose key is used.
+  #[serde(default)]
+  pub profile: Option<String>,
+  /// Key that authorizes registering new API keys.
+  #[serde(default)]
+  pub registration_key: Option<String>,
+  /// Settings that apply to a single profile, by name.
+  #[serde(default)]
+  pub profiles: BTreeMap<String, IrxProfile>,
+  /// Timeouts and retries for requests to the API.
+  #[serde(default)]
+  pub http: HttpConfig,
+  /// Proxy that requests to the API go through, overriding `HTTPS_PROXY`.
+  #[serde(default)]
+  pub proxy: Option<String>,
+  /// PEM files of CA certificates to trust on top of the system's.
+  #[serde(default)]
+  pub ca_certs: Vec<PathBuf>,
+}
+
+/// Configures how requests to the IRX API are timed out and retried.
+///
+/// Unset values keep their defaults.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct HttpConfig {
+  /// Seconds to wait for a connection to the API.
+  #[serde(default)]
+  pub connect_timeout_secs: Option<u64>,
+  /// Seconds to wait for the API to answer a request.
+  #[serde(default)]
+  pub timeout_secs: Option<u64>,
+  /// How many times a request that failed transiently is retried.
+  #[serde(default)]
+  pub retries: Option<u32>,
+}
+
+/// Configures a named IRX profile, e.g. `staging`.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct IrxProfile {
+  /// Base URL of the API for this profile, overriding `irx.endpoint`.
+  #[serde(default)]
+  pub endpoint: Option<String>,
+}
+
 /// Defines the application configuration properties.
 ///
-/// This structure holds paths for data and configuration directories.
+/// This structure holds paths for data and configuration directories, the
+/// root directory browsed for problem files, and the IRX client settings.
 #[derive(
   Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
 )]
@@ -64,6 +140,10 @@ pub struct AppConfig {
   pub _data_dir: PathBuf,
   #[serde(default)]
   pub _config_dir: PathBuf,
+  #[serde(default)]
+  pub problem_dir: Option<PathBuf>,
+  #[serde(default)]
+  pub irx: IrxConfig,
 }
 
 /// Represents the main configuration for the application.
@@ -0,0 +1,88 @@
+use super::api::{ApiClient, ApiKey, GetAccount, RegenerateKey, RevokeKey};
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+
+/// What the client knows about its account, as reported to the UI.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct Account {
+  /// The key's tier, once the API has told us.
+  pub tier: Option<String>,
+  /// Requests made to the API since Napali started.
+  pub session_requests: u64,
+  /// Requests made to the API overall.
+  pub lifetime_requests: u64,
+  /// How the last regenerate or revoke went.
+  pub notice: Option<String>,
+}
+
+/// Calls the account endpoints of the IRX API.
+#[derive(Debug, Clone)]
+pub struct AccountApi {
+  client: ApiClient,
+}
+
+impl AccountApi {
+  /// Creates a new `AccountApi` that calls the API through `client`.
+  pub fn new(client: ApiClient) -> Self {
+    Self { client }
+  }
+
+  /// Fetches the tier of the key.
+  pub async fn tier(&self) -> Result<String> {
+    Ok(self.client.call(GetAccount).await?.tier)
+  }
+
+  /// Replaces the key with a new one of the same tier.
+  ///
+  /// # Returns
+  /// The new key; the old one stops working.
+  pub async fn regenerate(&self) -> Result<ApiKey> {
+    Ok(self.client.call(RegenerateKey).await?.api_key_value)
+  }
+
+  /// Revokes the key, so that it stops working.
+  pub async fn revoke(&self) -> Result<()> {
+    self.client.call(RevokeKey).await?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::irx_client::{
+    http::{Http, HttpPolicy},
+    stub::{key, stub},
+    usage::Usage,
+  };
+  use pretty_assertions::assert_eq;
+  use std::sync::Arc;
+
+  #[tokio::test]
+  async fn test_account_api() -> Result<()> {
+    let (base, handle) = stub(vec![
+      r#"{"tier":"free"}"#,
+      r#"{"apiKeyValue":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"}"#,
+      "{}",
+    ])
+    .await;
+    let usage = Arc::new(Usage::default());
+    let api = AccountApi::new(ApiClient::new(
+      Http::new(HttpPolicy::default())?,
+      base,
+      key(),
+      usage.clone(),
+    ));
+    assert_eq!(api.tier().await?, "free");
+    assert_eq!(api.regenerate().await?.expose(), "a".repeat(40));
+    api.revoke().await?;
+    let requests = handle.await?;
+    assert_eq!(requests[0], "GET /account HTTP/1.1");
+    assert_eq!(requests[2], "POST /keys/regenerate HTTP/1.1");
+    assert_eq!(requests[4], "POST /keys/revoke HTTP/1.1");
+    assert_eq!(usage.session(), 3);
+    Ok(())
+  }
+}
@@ -2,17 +2,50 @@
 #![allow(clippy::needless_lifetimes)]
 #![allow(clippy::match_single_binding)]
 #![allow(clippy::clone_on_copy)]
+#![allow(clippy::derivable_impls)]
 
-use serde::Serialize;
+use super::{
+  http::{ApiError, Http},
+  usage::Usage,
+};
+use std::sync::Arc;
 
 #[doc = "... "]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct ApiKey(String);
-impl std::ops::Deref for ApiKey {
-  type Target = String;
-  fn deref(&self) -> &String {
+impl ApiKey {
+  /// Returns the key itself.
+  ///
+  /// Debug and Display show the key masked, so that it doesn't end up in
+  /// logs or screenshots; this is for the few places that need the secret.
+  pub fn expose(&self) -> &str {
     &self.0
   }
+
+  /// Hides most of the key, leaving enough to tell keys apart.
+  pub fn masked(&self) -> String {
+    let (head, tail) = self.0.split_at(4);
+    format!("{head}{}{}", "*".repeat(8), &tail[tail.len() - 4..])
+  }
+}
+impl std::fmt::Display for ApiKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.masked())
+  }
+}
+impl std::fmt::Debug for ApiKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("ApiKey").field(&self.masked()).finish()
+  }
+}
+impl serde::Serialize for ApiKey {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    // The real key, so that it round-trips; only Debug and Display mask it
+    serializer.serialize_str(&self.0)
+  }
 }
 impl From<ApiKey> for String {
   fn from(value: ApiKey) -> Self {
@@ -73,3 +106,170 @@ impl<'de> serde::Deserialize<'de> for ApiKey {
       })
   }
 }
+
+/// An operation of the IRX API.
+///
+/// Each operation in `openapi.json` gets a struct implementing this, holding
+/// its path parameters and request body.
+pub trait Endpoint {
+  /// The operation's HTTP method.
+  const METHOD: reqwest::Method;
+  /// The request body, `()` for operations without one.
+  type Body: serde::Serialize;
+  /// The response body.
+  type Response: serde::de::DeserializeOwned;
+
+  /// Returns the path, relative to the API's base URL.
+  fn path(&self) -> String;
+
+  /// Returns the request body, if there's one to send.
+  fn body(&self) -> Option<&Self::Body>;
+}
+
+/// The response of operations that return nothing of interest.
+pub type NoContent = serde::de::IgnoredAny;
+
+/// A JSON document the API sends encoded as a string, decoded into `T`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Enveloped<T>(pub T);
+
+impl<'de, T: serde::de::DeserializeOwned> serde::Deserialize<'de>
+  for Enveloped<T>
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let json = String::deserialize(deserializer)?;
+    serde_json::from_str(&json)
+      .map(Enveloped)
+      .map_err(<D::Error as serde::de::Error>::custom)
+  }
+}
+impl<T: serde::Serialize> serde::Serialize for Enveloped<T> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let json = serde_json::to_string(&self.0)
+      .map_err(<S::Error as serde::ser::Error>::custom)?;
+    serializer.serialize_str(&json)
+  }
+}
+
+/// Models and endpoints generated from `openapi.json` by `build.rs`.
+#[allow(dead_code)]
+mod generated {
+  use super::{ApiKey, Endpoint, Enveloped, NoContent};
+  include!(concat!(env!("OUT_DIR"), "/irx_api.rs"));
+}
+pub use generated::*;
+
+/// Calls an endpoint, authenticated with `key`.
+///
+/// # Returns
+/// The parsed response body, or an error if the request fails or the API
+/// responds with an error status. Failures of the request itself are
+/// `ApiError`s.
+pub async fn call<E: Endpoint>(
+  http: &Http,
+  base: &url::Url,
+  key: &ApiKey,
+  endpoint: &E,
+) -> color_eyre::Result<E::Response> {
+  let path = endpoint.path();
+  let url = base
+    .join(&path)
+    .map_err(|e| color_eyre::eyre::eyre!("invalid url {path:?}: {e}"))?;
+  let mut request = http
+    .client()
+    .request(E::METHOD, url)
+    .header("x-api-key", key.expose());
+  if let Some(body) = endpoint.body() {
+    request = request.json(body);
+  }
+  let response = http.send(request).await?;
+  let bytes = response.bytes().await.map_err(ApiError::from)?;
+  // An empty body is as good as `null`, which `NoContent` accepts
+  let bytes = if bytes.is_empty() {
+    b"null"
+  } else {
+    &bytes[..]
+  };
+  serde_json::from_slice(bytes)
+    .map_err(|e| ApiError::InvalidResponse(e.to_string()).into())
+}
+
+/// Calls endpoints of the IRX API on behalf of one key.
+///
+/// Every request is authenticated with the `ApiKey` and counted.
+#[derive(Debug, Clone)]
+pub struct ApiClient {
+  http: Http,
+  base: url::Url,
+  key: ApiKey,
+  usage: Arc<Usage>,
+}
+
+impl ApiClient {
+  /// Creates a new `ApiClient` for the API rooted at `base`.
+  pub fn new(
+    http: Http,
+    base: url::Url,
+    key: ApiKey,
+    usage: Arc<Usage>,
+  ) -> Self {
+    Self {
+      http,
+      base,
+      key,
+      usage,
+    }
+  }
+
+  /// Calls an endpoint, and counts the request.
+  pub async fn call<E: Endpoint>(
+    &self,
+    endpoint: E,
+  ) -> color_eyre::Result<E::Response> {
+    self.usage.record();
+    call(&self.http, &self.base, &self.key, &endpoint).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  const KEY: &str = "AbCdEfGhIjKlMnOpQrStUvWxYz0123456789wxyz";
+
+  #[test]
+  fn test_api_key_is_redacted() {
+    let key: ApiKey = KEY.parse().unwrap();
+    assert_eq!(key.expose(), KEY);
+    assert_eq!(key.to_string(), "AbCd********wxyz");
+    assert_eq!(format!("{key:?}"), "ApiKey(\"AbCd********wxyz\")");
+  }
+
+  #[test]
+  fn test_api_key_round_trips() {
+    let key: ApiKey = KEY.parse().unwrap();
+    let json = serde_json::to_string(&key).unwrap();
+    assert_eq!(json, format!("\"{KEY}\""));
+    assert_eq!(serde_json::from_str::<ApiKey>(&json).unwrap(), key);
+  }
+
+  #[test]
+  fn test_enveloped_body() {
+    let json = format!(
+      r#"{{"statusCode":200,"body":"{{\"apiKeyId\":\"id\",\"apiKeyValue\":\"{KEY}\"}}"}}"#
+    );
+    let response: RegisterResponse = serde_json::from_str(&json).unwrap();
+    assert_eq!(response.body.0.api_key_value.expose(), KEY);
+    assert!(serde_json::from_str::<RegisterResponse>(
+      r#"{"statusCode":200,"body":"{\"apiKeyId\":\"id\"}"}"#
+    )
+    .is_err());
+  }
+}
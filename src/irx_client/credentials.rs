@@ -0,0 +1,356 @@
+use super::api::ApiKey;
+use argon2::Argon2;
+use chacha20poly1305::{
+  aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+  ChaCha20Poly1305, Nonce,
+};
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, fs, path::PathBuf};
+
+/// Environment variable holding the passphrase that encrypts stored keys.
+pub const PASSPHRASE_ENV: &str = "IRX_KEY_PASSPHRASE";
+
+/// Name of the profile used when none is chosen.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// A passphrase for encrypting stored keys, kept out of `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+  /// Wraps a passphrase, ignoring empty ones.
+  pub fn new(passphrase: String) -> Option<Passphrase> {
+    (!passphrase.is_empty()).then_some(Passphrase(passphrase))
+  }
+}
+
+impl fmt::Debug for Passphrase {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Passphrase(***)")
+  }
+}
+
+/// A stored key, as it appears in the credential file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Entry {
+  /// The key in plain text.
+  Plain { key: String },
+  /// The key encrypted with a passphrase, all fields hex-encoded.
+  Encrypted {
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+  },
+}
+
+/// The API keys on disk, one per named profile.
+///
+/// The credential file is either a bare key, which is the `default`
+/// profile's key in plain text, or a JSON object of profiles. A bare key is
+/// kept as is for as long as it's the only one and no passphrase is set, so
+/// that other tools reading the file keep working.
+///
+/// With a passphrase, keys are written encrypted with ChaCha20-Poly1305
+/// under a key derived from the passphrase with Argon2. The file is always
+/// written readable by its owner only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialStore {
+  /// The credential file.
+  pub path: PathBuf,
+  /// The profile whose key is read and written.
+  pub profile: String,
+  /// Passphrase for encrypted keys, if any.
+  passphrase: Option<Passphrase>,
+}
+
+impl CredentialStore {
+  /// Length of the salt used to derive encryption keys.
+  const SALT_LEN: usize = 16;
+  /// Length of the nonce keys are encrypted with.
+  const NONCE_LEN: usize = 12;
+
+  /// Creates a store for one profile of a credential file.
+  pub fn new(
+    path: PathBuf,
+    profile: String,
+    passphrase: Option<Passphrase>,
+  ) -> CredentialStore {
+    CredentialStore {
+      path,
+      profile,
+      passphrase,
+    }
+  }
+
+  /// Reads the profile's key.
+  ///
+  /// # Returns
+  /// The key, `None` if there's none, or an error if it's there but can't
+  /// be read, e.g. because it's encrypted and the passphrase is wrong.
+  pub fn read(&self) -> Result<Option<ApiKey>> {
+    let Some(entry) = self.entries()?.remove(&self.profile) else {
+      return Ok(None);
+    };
+    let key = match entry {
+      Entry::Plain { key } => key,
+      Entry::Encrypted {
+        salt,
+        nonce,
+        ciphertext,
+      } => {
+        let passphrase = self.passphrase.as_ref().ok_or_else(|| {
+          eyre!("the key is encrypted; set ${PASSPHRASE_ENV} to unlock it")
+        })?;
+        let (salt, nonce) = (decode(&salt)?, decode(&nonce)?);
+        if salt.len() != Self::SALT_LEN || nonce.len() != Self::NONCE_LEN {
+          return Err(eyre!("the encrypted key is corrupt"));
+        }
+        let plaintext = Self::cipher(passphrase, &salt)?
+          .decrypt(Nonce::from_slice(&nonce), &*decode(&ciphertext)?)
+          .map_err(|_| eyre!("failed to decrypt the key: wrong passphrase?"))?;
+        String::from_utf8(plaintext)?
+      }
+    };
+    key.parse().map(Some).map_err(|e| eyre!("invalid key: {e}"))
+  }
+
+  /// Writes the profile's key, keeping the other profiles.
+  pub fn write(&self, key: &ApiKey) -> Result<()> {
+    let mut entries = self.entries()?;
+    let entry = match &self.passphrase {
+      Some(passphrase) => {
+        let mut salt = [0; Self::SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = Self::cipher(passphrase, &salt)?
+          .encrypt(&nonce, key.expose().as_bytes())
+          .map_err(|e| eyre!("failed to encrypt the key: {e}"))?;
+        Entry::Encrypted {
+          salt: encode(&salt),
+          nonce: encode(&nonce),
+          ciphertext: encode(&ciphertext),
+        }
+      }
+      None => Entry::Plain {
+        key: key.expose().to_string(),
+      },
+    };
+    entries.insert(self.profile.clone(), entry);
+    self.save(&entries)
+  }
+
+  /// Removes the profile's key, and the file once no key is left.
+  pub fn remove(&self) -> Result<()> {
+    let mut entries = self.entries()?;
+    if entries.remove(&self.profile).is_none() {
+      return Ok(());
+    }
+    if entries.is_empty() {
+      fs::remove_file(&self.path)?;
+      return Ok(());
+    }
+    self.save(&entries)
+  }
+
+  /// Returns a warning if others can read the credential file.
+  pub fn check_permissions(&self) -> Option<String> {
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::PermissionsExt;
+      let mode = fs::metadata(&self.path).ok()?.permissions().mode();
+      if mode & 0o077 != 0 {
+        return Some(format!(
+          "{} is readable by others (mode {:o}); run chmod 600 on it",
+          self.path.display(),
+          mode & 0o777
+        ));
+      }
+    }
+    None
+  }
+
+  /// Reads all the profiles in the credential file.
+  fn entries(&self) -> Result<BTreeMap<String, Entry>> {
+    let contents = match fs::read_to_string(&self.path) {
+      Ok(contents) => contents,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+        return Ok(BTreeMap::new())
+      }
+      Err(e) => return Err(e.into()),
+    };
+    let contents = contents.trim();
+    if contents.starts_with('{') {
+      serde_json::from_str(contents)
+        .map_err(|e| eyre!("invalid credential file: {e}"))
+    } else if contents.is_empty() {
+      Ok(BTreeMap::new())
+    } else {
+      let key = Entry::Plain {
+        key: contents.to_string(),
+      };
+      Ok(BTreeMap::from([(String::from(DEFAULT_PROFILE), key)]))
+    }
+  }
+
+  /// Writes all the profiles to the credential file, readable by its owner
+  /// only.
+  fn save(&self, entries: &BTreeMap<String, Entry>) -> Result<()> {
+    let contents = match entries.get(DEFAULT_PROFILE) {
+      Some(Entry::Plain { key }) if entries.len() == 1 => key.clone(),
+      _ => serde_json::to_string_pretty(entries)?,
+    };
+    let parent = self
+      .path
+      .parent()
+      .ok_or_else(|| eyre!("failed to get key file directory"))?;
+    fs::create_dir_all(parent)?;
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+      options.mode(0o600);
+      // The mode only applies to new files
+      if self.path.exists() {
+        fs::set_permissions(&self.path, fs::Permissions::from_mode(0o600))?;
+      }
+    }
+    std::io::Write::write_all(
+      &mut options.open(&self.path)?,
+      contents.as_bytes(),
+    )?;
+    Ok(())
+  }
+
+  /// Derives the cipher for a passphrase and salt.
+  fn cipher(passphrase: &Passphrase, salt: &[u8]) -> Result<ChaCha20Poly1305> {
+    let mut key = [0; 32];
+    Argon2::default()
+      .hash_password_into(passphrase.0.as_bytes(), salt, &mut key)
+      .map_err(|e| eyre!("failed to derive the encryption key: {e}"))?;
+    Ok(ChaCha20Poly1305::new(&key.into()))
+  }
+}
+
+/// Encodes bytes as lowercase hex.
+fn encode(bytes: &[u8]) -> String {
+  bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Decodes lowercase or uppercase hex.
+fn decode(hex: &str) -> Result<Vec<u8>> {
+  hex
+    .as_bytes()
+    .chunks(2)
+    .map(|pair| {
+      std::str::from_utf8(pair)
+        .ok()
+        .filter(|pair| pair.len() == 2)
+        .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        .ok_or_else(|| eyre!("invalid hex {hex:?}"))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::irx_client::stub::key;
+  use pretty_assertions::assert_eq;
+  use std::path::Path;
+
+  fn store(
+    dir: &Path,
+    profile: &str,
+    passphrase: Option<&str>,
+  ) -> CredentialStore {
+    CredentialStore::new(
+      dir.join("key.txt"),
+      String::from(profile),
+      passphrase.and_then(|p| Passphrase::new(String::from(p))),
+    )
+  }
+
+  fn temp_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("napali-{name}-{}", std::process::id()))
+  }
+
+  #[test]
+  fn test_credentials_profiles() -> Result<()> {
+    let dir = temp_dir("credentials-profiles");
+    let default = store(&dir, DEFAULT_PROFILE, None);
+    assert_eq!(default.read()?, None);
+    default.write(&key())?;
+    // A lone plain key stays a bare key
+    assert_eq!(fs::read_to_string(&default.path)?, key().expose());
+    assert_eq!(default.check_permissions(), None);
+
+    let staging = store(&dir, "staging", None);
+    let other: ApiKey = "a".repeat(40).parse().unwrap();
+    staging.write(&other)?;
+    assert_eq!(default.read()?, Some(key()));
+    assert_eq!(staging.read()?, Some(other));
+
+    default.remove()?;
+    assert_eq!(default.read()?, None);
+    staging.remove()?;
+    assert!(!staging.path.exists());
+    fs::remove_dir_all(dir).ok();
+    Ok(())
+  }
+
+  #[test]
+  fn test_credentials_encrypted() -> Result<()> {
+    let dir = temp_dir("credentials-encrypted");
+    store(&dir, DEFAULT_PROFILE, Some("hunter2")).write(&key())?;
+    let contents = fs::read_to_string(dir.join("key.txt"))?;
+    assert!(!contents.contains(key().expose()));
+
+    assert_eq!(
+      store(&dir, DEFAULT_PROFILE, Some("hunter2")).read()?,
+      Some(key())
+    );
+    assert!(store(&dir, DEFAULT_PROFILE, Some("wrong")).read().is_err());
+    assert!(store(&dir, DEFAULT_PROFILE, None).read().is_err());
+    fs::remove_dir_all(dir).ok();
+    Ok(())
+  }
+
+  #[test]
+  fn test_credentials_corrupt() -> Result<()> {
+    let dir = temp_dir("credentials-corrupt");
+    let store = store(&dir, DEFAULT_PROFILE, Some("hunter2"));
+    store.write(&key())?;
+    let contents = fs::read_to_string(&store.path)?;
+    let mut entries: serde_json::Value = serde_json::from_str(&contents)?;
+    entries[DEFAULT_PROFILE]["nonce"] = "0011".into();
+    fs::write(&store.path, entries.to_string())?;
+    assert_eq!(
+      store.read().unwrap_err().to_string(),
+      "the encrypted key is corrupt"
+    );
+    fs::remove_dir_all(dir).ok();
+    Ok(())
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_credentials_permissions() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = temp_dir("credentials-permissions");
+    let store = store(&dir, DEFAULT_PROFILE, None);
+    fs::create_dir_all(&dir)?;
+    fs::write(&store.path, key().expose())?;
+    fs::set_permissions(&store.path, fs::Permissions::from_mode(0o644))?;
+    assert!(store.check_permissions().is_some());
+
+    store.write(&key())?;
+    let mode = fs::metadata(&store.path)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    assert_eq!(store.check_permissions(), None);
+    fs::remove_dir_all(dir).ok();
+    Ok(())
+  }
+}
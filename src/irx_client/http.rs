@@ -0,0 +1,489 @@
+use super::settings::Settings;
+use crate::config::HttpConfig;
+use color_eyre::eyre::{eyre, Result};
+use reqwest::{header::HeaderMap, Certificate, Method, StatusCode};
+use std::{
+  fmt,
+  path::Path,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// How requests to the IRX API are timed out and retried.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HttpPolicy {
+  /// How long to wait for a connection to the API.
+  pub connect_timeout: Duration,
+  /// How long to wait for the API to answer a request.
+  pub timeout: Duration,
+  /// How many times a request that failed transiently is retried.
+  pub retries: u32,
+  /// How long to wait before the first retry; each retry waits twice as
+  /// long as the one before.
+  pub backoff: Duration,
+  /// The longest the client waits before retrying. If the API asks to wait
+  /// longer, the request fails instead.
+  pub max_delay: Duration,
+}
+
+impl Default for HttpPolicy {
+  fn default() -> Self {
+    Self {
+      connect_timeout: Duration::from_secs(5),
+      timeout: Duration::from_secs(30),
+      retries: 3,
+      backoff: Duration::from_millis(500),
+      max_delay: Duration::from_secs(30),
+    }
+  }
+}
+
+impl HttpPolicy {
+  /// Applies the `irx.http` section of the config file to the defaults.
+  pub fn from_config(config: &HttpConfig) -> Self {
+    let default = Self::default();
+    Self {
+      connect_timeout: config
+        .connect_timeout_secs
+        .map_or(default.connect_timeout, Duration::from_secs),
+      timeout: config
+        .timeout_secs
+        .map_or(default.timeout, Duration::from_secs),
+      retries: config.retries.unwrap_or(default.retries),
+      ..default
+    }
+  }
+
+  /// Returns how long to wait before the given retry, counting from 0.
+  fn delay(&self, attempt: u32) -> Duration {
+    self
+      .backoff
+      .saturating_mul(2u32.saturating_pow(attempt))
+      .min(self.max_delay)
+  }
+}
+
+/// Why a request to the IRX API failed, in terms a user can act on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+  /// No connection could be made to the API.
+  Unreachable,
+  /// The API didn't answer in time.
+  Timeout,
+  /// The API key is missing, invalid or revoked.
+  Unauthorized,
+  /// Too many requests were made; the API may say how long to wait.
+  RateLimited(Option<Duration>),
+  /// The API failed to handle the request.
+  Server(StatusCode),
+  /// The API refused the request, with its explanation if it gave one.
+  Rejected(StatusCode, String),
+  /// The API's answer couldn't be understood.
+  InvalidResponse(String),
+  /// The request couldn't be built, e.g. because its URL is malformed.
+  InvalidRequest(String),
+}
+
+impl ApiError {
+  /// Longest explanation of a refused request that is kept.
+  const MAX_MESSAGE: usize = 200;
+
+  /// Classifies an error response.
+  async fn from_response(response: reqwest::Response) -> ApiError {
+    let status = response.status();
+    match status {
+      StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+        ApiError::Unauthorized
+      }
+      StatusCode::TOO_MANY_REQUESTS => ApiError::RateLimited(retry_after(
+        response.headers(),
+        SystemTime::now(),
+      )),
+      _ if status.is_server_error() => ApiError::Server(status),
+      _ => {
+        let message = response.text().await.unwrap_or_default();
+        let message = message.trim().chars().take(Self::MAX_MESSAGE).collect();
+        ApiError::Rejected(status, message)
+      }
+    }
+  }
+
+  /// Returns whether trying again later might succeed.
+  fn is_transient(&self) -> bool {
+    matches!(
+      self,
+      ApiError::Unreachable | ApiError::RateLimited(_) | ApiError::Server(_)
+    )
+  }
+}
+
+impl From<reqwest::Error> for ApiError {
+  fn from(error: reqwest::Error) -> ApiError {
+    // Connection timeouts are connection errors, and safe to retry; a
+    // request that timed out may have been handled already.
+    if error.is_builder() {
+      ApiError::InvalidRequest(error.to_string())
+    } else if error.is_connect() {
+      ApiError::Unreachable
+    } else if error.is_timeout() {
+      ApiError::Timeout
+    } else if error.is_decode() || error.is_body() {
+      ApiError::InvalidResponse(error.to_string())
+    } else {
+      ApiError::Unreachable
+    }
+  }
+}
+
+impl fmt::Display for ApiError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ApiError::Unreachable => {
+        write!(f, "the API can't be reached; check your connection")
+      }
+      ApiError::Timeout => write!(f, "the API took too long to answer"),
+      ApiError::Unauthorized => {
+        write!(f, "the API key was rejected; it may have been revoked")
+      }
+      ApiError::RateLimited(Some(delay)) => write!(
+        f,
+        "too many requests; try again in {}s",
+        delay.as_secs().max(1)
+      ),
+      ApiError::RateLimited(None) => {
+        write!(f, "too many requests; try again later")
+      }
+      ApiError::Server(status) => {
+        write!(f, "the API failed ({status}); try again later")
+      }
+      ApiError::Rejected(status, message) if message.is_empty() => {
+        write!(f, "the API refused the request ({status})")
+      }
+      ApiError::Rejected(status, message) => {
+        write!(f, "the API refused the request ({status}): {message}")
+      }
+      ApiError::InvalidResponse(reason) => {
+        write!(f, "the API's answer couldn't be understood: {reason}")
+      }
+      ApiError::InvalidRequest(reason) => {
+        write!(f, "the request couldn't be made: {reason}")
+      }
+    }
+  }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Reads how long the API asks to wait before the next request.
+///
+/// `Retry-After` is honoured in its delay-seconds form, and failing that,
+/// `X-RateLimit-Reset`. Values too large to be a delay are taken as the Unix
+/// time to wait until, as `X-RateLimit-Reset` usually is.
+fn retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+  /// Seconds since the epoch in 2001; no API asks to wait that long.
+  const EPOCH_TIME: u64 = 1_000_000_000;
+  let secs: u64 = ["retry-after", "x-ratelimit-reset"]
+    .iter()
+    .find_map(|name| headers.get(*name)?.to_str().ok()?.trim().parse().ok())?;
+  if secs < EPOCH_TIME {
+    return Some(Duration::from_secs(secs));
+  }
+  let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+  Some(Duration::from_secs(secs).saturating_sub(now))
+}
+
+/// Reads every certificate in a PEM file.
+fn certificates(path: &Path) -> Result<Vec<Certificate>> {
+  let pem = std::fs::read(path).map_err(|e| {
+    eyre!("failed to read CA certificates {}: {e}", path.display())
+  })?;
+  let certificates = Certificate::from_pem_bundle(&pem)
+    .map_err(|e| eyre!("invalid CA certificates in {}: {e}", path.display()))?;
+  if certificates.is_empty() {
+    return Err(eyre!("no PEM certificates in {}", path.display()));
+  }
+  Ok(certificates)
+}
+
+/// The HTTP layer shared by everything that calls the IRX API.
+///
+/// Cloning it is cheap, and clones share a pool of connections.
+#[derive(Debug, Clone)]
+pub struct Http {
+  client: reqwest::Client,
+  policy: HttpPolicy,
+  /// Why the proxy or CA certificates couldn't be used, if they couldn't.
+  problem: Option<String>,
+}
+
+impl Http {
+  /// Creates the HTTP layer with the given policy.
+  ///
+  /// Proxies are taken from the environment: `HTTPS_PROXY`, `HTTP_PROXY`
+  /// and `ALL_PROXY`, except for hosts in `NO_PROXY`.
+  pub fn new(policy: HttpPolicy) -> Result<Self> {
+    Self::build(reqwest::Client::builder(), policy)
+  }
+
+  /// Creates the HTTP layer for the resolved settings.
+  ///
+  /// Requests go through `irx.proxy` if it's set, still reaching hosts in
+  /// `NO_PROXY` directly, and trust the certificates in `irx.ca_certs` on
+  /// top of the system's. If either can't be used, the layer is created
+  /// without them and `problem` says why, so that it can be shown rather
+  /// than stop Napali from starting.
+  pub fn configure(settings: &Settings) -> Result<Self> {
+    match Self::configured(settings) {
+      Ok(builder) => Self::build(builder, settings.http),
+      Err(e) => {
+        log::error!("{e}; the IRX API won't be contacted");
+        Ok(Self {
+          problem: Some(e.to_string()),
+          ..Self::new(settings.http)?
+        })
+      }
+    }
+  }
+
+  /// Applies the proxy and CA certificates of the settings.
+  fn configured(settings: &Settings) -> Result<reqwest::ClientBuilder> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy) = &settings.proxy {
+      let proxy = reqwest::Proxy::all(proxy.as_str())
+        .map_err(|e| eyre!("invalid proxy {proxy:?}: {e}"))?
+        .no_proxy(reqwest::NoProxy::from_env());
+      builder = builder.proxy(proxy);
+    }
+    for path in &settings.ca_certs {
+      for certificate in certificates(path)? {
+        builder = builder.add_root_certificate(certificate);
+      }
+    }
+    Ok(builder)
+  }
+
+  /// Finishes building the client with the policy's timeouts.
+  fn build(
+    builder: reqwest::ClientBuilder,
+    policy: HttpPolicy,
+  ) -> Result<Self> {
+    let client = builder
+      .connect_timeout(policy.connect_timeout)
+      .timeout(policy.timeout)
+      .build()?;
+    Ok(Self {
+      client,
+      policy,
+      problem: None,
+    })
+  }
+
+  /// Returns why the proxy or CA certificates couldn't be used, if they
+  /// couldn't.
+  pub fn problem(&self) -> Option<&str> {
+    self.problem.as_deref()
+  }
+
+  /// Returns the underlying client, for building requests.
+  pub fn client(&self) -> &reqwest::Client {
+    &self.client
+  }
+
+  /// Sends a request, retrying it while it fails transiently.
+  ///
+  /// Connection errors, server errors and rate limiting are retried up to
+  /// `HttpPolicy::retries` times, backing off exponentially or waiting as
+  /// long as the API asks to. Requests that aren't idempotent, such as
+  /// submitting a job, are only retried when the API can't have handled
+  /// them: when no connection could be made, or when it asked for the
+  /// request to be made again later.
+  ///
+  /// # Returns
+  /// The successful response, or why the request failed.
+  pub async fn send(
+    &self,
+    request: reqwest::RequestBuilder,
+  ) -> Result<reqwest::Response, ApiError> {
+    let request = request
+      .build()
+      .map_err(|e| ApiError::InvalidRequest(e.to_string()))?;
+    let idempotent = matches!(*request.method(), Method::GET | Method::DELETE);
+    // Each attempt sends a copy, which streamed bodies can't be
+    let copy = || {
+      request.try_clone().ok_or_else(|| {
+        ApiError::InvalidRequest(String::from("its body can't be resent"))
+      })
+    };
+    let mut next = copy()?;
+    let mut attempt = 0;
+    loop {
+      let (error, handled, wait) = match self.client.execute(next).await {
+        Ok(response) if response.status().is_success() => return Ok(response),
+        Ok(response) => {
+          // The API asks for these to be made again later, unhandled
+          let status = response.status();
+          let again = status == StatusCode::TOO_MANY_REQUESTS
+            || status == StatusCode::SERVICE_UNAVAILABLE
+              && response.headers().contains_key("retry-after");
+          let wait = retry_after(response.headers(), SystemTime::now())
+            .filter(|_| again);
+          (ApiError::from_response(response).await, !again, wait)
+        }
+        Err(e) => {
+          let handled = !e.is_connect();
+          (ApiError::from(e), handled, None)
+        }
+      };
+      let retry = error.is_transient() && (idempotent || !handled);
+      if !retry || attempt >= self.policy.retries {
+        return Err(error);
+      }
+      let delay = wait.unwrap_or_else(|| self.policy.delay(attempt));
+      if delay > self.policy.max_delay {
+        return Err(error);
+      }
+      log::warn!(
+        "{} {}: {error}; retrying in {delay:?}",
+        request.method(),
+        request.url()
+      );
+      tokio::time::sleep(delay).await;
+      next = copy()?;
+      attempt += 1;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::irx_client::stub::stub_with_status;
+  use pretty_assertions::assert_eq;
+  use reqwest::header::HeaderValue;
+
+  fn http(retries: u32) -> Http {
+    Http::new(HttpPolicy {
+      retries,
+      backoff: Duration::from_millis(1),
+      ..HttpPolicy::default()
+    })
+    .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_retries_transient_errors() {
+    let (base, handle) =
+      stub_with_status(vec![(503, ""), (429, ""), (200, "{}")]).await;
+    let http = http(2);
+    let response = http.send(http.client().get(base)).await;
+    assert_eq!(response.map(|r| r.status()), Ok(StatusCode::OK));
+    assert_eq!(handle.await.unwrap().len(), 3);
+  }
+
+  #[tokio::test]
+  async fn test_retries_posts_only_unhandled() {
+    let (base, handle) = stub_with_status(vec![(503, "")]).await;
+    let http = http(2);
+    let response = http.send(http.client().post(base)).await;
+    assert_eq!(
+      response.unwrap_err(),
+      ApiError::Server(StatusCode::SERVICE_UNAVAILABLE)
+    );
+    assert_eq!(handle.await.unwrap().len(), 1);
+
+    let (base, handle) = stub_with_status(vec![(429, ""), (200, "{}")]).await;
+    let response = http.send(http.client().post(base)).await;
+    assert_eq!(response.map(|r| r.status()), Ok(StatusCode::OK));
+    assert_eq!(handle.await.unwrap().len(), 2);
+
+    let unreachable = http.client().post("http://127.0.0.1:9/");
+    assert_eq!(
+      http.send(unreachable).await.unwrap_err(),
+      ApiError::Unreachable
+    );
+  }
+
+  #[tokio::test]
+  async fn test_classifies_errors() {
+    let (base, handle) = stub_with_status(vec![
+      (401, ""),
+      (400, "missing solver"),
+      (500, ""),
+      (500, ""),
+    ])
+    .await;
+    let http = http(1);
+    let send = || http.send(http.client().get(base.clone()));
+    assert_eq!(send().await.unwrap_err(), ApiError::Unauthorized);
+    assert_eq!(
+      send().await.unwrap_err().to_string(),
+      "the API refused the request (400 Bad Request): missing solver"
+    );
+    assert_eq!(
+      send().await.unwrap_err(),
+      ApiError::Server(StatusCode::INTERNAL_SERVER_ERROR)
+    );
+    assert_eq!(handle.await.unwrap().len(), 4);
+
+    let unreachable = http.client().get("http://127.0.0.1:9/");
+    assert_eq!(
+      http.send(unreachable).await.unwrap_err(),
+      ApiError::Unreachable
+    );
+
+    let malformed = http.client().get("not a url");
+    assert!(matches!(
+      http.send(malformed).await.unwrap_err(),
+      ApiError::InvalidRequest(_)
+    ));
+  }
+
+  #[test]
+  fn test_configure() {
+    let settings = |proxy: &str, ca_certs: Vec<&str>| Settings {
+      proxy: Some(String::from(proxy)),
+      ca_certs: ca_certs.into_iter().map(Into::into).collect(),
+      ..Settings::resolve(
+        None,
+        None,
+        None,
+        None,
+        None,
+        false,
+        &Default::default(),
+      )
+      .unwrap()
+    };
+    let http = Http::configure(&settings("http://proxy.corp:3128", vec![]));
+    assert_eq!(http.unwrap().problem(), None);
+
+    let http = Http::configure(&settings("http://[::1", vec![])).unwrap();
+    assert!(http.problem().unwrap().starts_with("invalid proxy"));
+
+    let missing = "/nonexistent/ca.pem";
+    let http =
+      Http::configure(&settings("http://proxy.corp:3128", vec![missing]));
+    assert_eq!(
+      http.unwrap().problem(),
+      Some("failed to read CA certificates /nonexistent/ca.pem: No such file or directory (os error 2)")
+    );
+  }
+
+  #[test]
+  fn test_retry_after() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let mut headers = HeaderMap::new();
+    assert_eq!(retry_after(&headers, now), None);
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("7"));
+    assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(7)));
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000012"));
+    assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(12)));
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("1699999990"));
+    assert_eq!(retry_after(&headers, now), Some(Duration::ZERO));
+    headers.insert("retry-after", HeaderValue::from_static("2"));
+    assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(2)));
+
+    let policy = HttpPolicy::default();
+    assert_eq!(policy.delay(1), Duration::from_secs(1));
+    assert_eq!(policy.delay(10), policy.max_delay);
+  }
+}
@@ -0,0 +1,322 @@
+use super::api::{
+  self, ApiClient, CancelJob, GetJob, GetJobResult, JobResult, RemoteState,
+  SubmitJob, SubmitJobRequest,
+};
+use crate::job::{
+  parser, JobSpec, JobState, Objective, Progress, Solution, Solver,
+  SolverEvent, SolverParams,
+};
+use crate::router::{Address, Cacheable, Kind, Message, Payload};
+use color_eyre::eyre::{eyre, Result};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// How long to wait between status requests for a remote job.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+impl From<RemoteState> for JobState {
+  fn from(state: RemoteState) -> JobState {
+    match state {
+      RemoteState::Queued => JobState::Queued,
+      RemoteState::Running => JobState::Running,
+      RemoteState::Finished => JobState::Finished,
+      RemoteState::Failed => JobState::Failed,
+      RemoteState::Cancelled => JobState::Cancelled,
+    }
+  }
+}
+
+impl From<api::JobProgress> for Progress {
+  fn from(progress: api::JobProgress) -> Progress {
+    Progress {
+      elapsed: Duration::from_secs_f64(progress.elapsed.max(0.0)),
+      primal: progress.primal_bound.map(Objective),
+      dual: progress.dual_bound.map(Objective),
+    }
+  }
+}
+
+/// Calls the job endpoints of the IRX API.
+#[derive(Debug, Clone)]
+pub struct JobsApi {
+  client: ApiClient,
+}
+
+impl JobsApi {
+  /// Creates a new `JobsApi` that calls the API through `client`.
+  pub fn new(client: ApiClient) -> Self {
+    Self { client }
+  }
+
+  /// Uploads a problem and starts solving it.
+  ///
+  /// # Returns
+  /// The identifier the API assigned to the job.
+  pub async fn submit(
+    &self,
+    name: &str,
+    solver: Solver,
+    params: &SolverParams,
+    problem: String,
+  ) -> Result<String> {
+    let body = SubmitJobRequest {
+      name: name.to_string(),
+      solver: solver.executable().to_string(),
+      problem,
+      params: Some(api::SolverParams {
+        time_limit: params.time_limit,
+        mip_gap: params.mip_gap.map(f64::from),
+        threads: params.threads,
+        presolve: Some(params.presolve),
+      }),
+    };
+    Ok(self.client.call(SubmitJob { body }).await?.job_id)
+  }
+
+  /// Fetches the current state of a job, and its progress once it's
+  /// running.
+  pub async fn status(
+    &self,
+    job_id: &str,
+  ) -> Result<(JobState, Option<Progress>)> {
+    let job_id = job_id.to_string();
+    let status = self.client.call(GetJob { job_id }).await?;
+    Ok((status.state.into(), status.progress.map(Progress::from)))
+  }
+
+  /// Asks the API to stop a job.
+  pub async fn cancel(&self, job_id: &str) -> Result<()> {
+    let job_id = job_id.to_string();
+    self.client.call(CancelJob { job_id }).await?;
+    Ok(())
+  }
+
+  /// Fetches the outcome of a finished job.
+  pub async fn result(&self, job_id: &str) -> Result<JobResult> {
+    let job_id = job_id.to_string();
+    self.client.call(GetJobResult { job_id }).await
+  }
+}
+
+/// A job submitted to the IRX API and where to report on it.
+pub struct RemoteJob {
+  pub spec: JobSpec,
+  pub reply_to: Address,
+  pub tx: mpsc::UnboundedSender<Message>,
+}
+
+impl RemoteJob {
+  /// Submits the job, then polls it until it stops or is cancelled.
+  ///
+  /// The token is cancelled when the job stops, which also lets the client
+  /// forget about it.
+  pub async fn execute(self, api: JobsApi, token: CancellationToken) {
+    let state = match self.track(&api, &token).await {
+      Ok(state) => state,
+      Err(e) => {
+        self.log(format!("{e}"));
+        JobState::Failed
+      }
+    };
+    self.state(state);
+    token.cancel();
+  }
+
+  /// Fails the job without submitting it.
+  pub fn reject(&self, reason: &str) {
+    self.log(reason.to_string());
+    self.state(JobState::Failed);
+  }
+
+  /// Reports state changes until the job stops.
+  async fn track(
+    &self,
+    api: &JobsApi,
+    token: &CancellationToken,
+  ) -> Result<JobState> {
+    let problem = tokio::fs::read_to_string(&self.spec.problem)
+      .await
+      .map_err(|e| {
+        eyre!("failed to read {}: {e}", self.spec.problem.display())
+      })?;
+    let name = self
+      .spec
+      .problem
+      .file_stem()
+      .map(|s| s.to_string_lossy().to_string())
+      .unwrap_or_default();
+    let spec = &self.spec;
+    let job_id = api
+      .submit(&name, spec.solver, &spec.params, problem)
+      .await?;
+    self.log(format!("submitted as {job_id}"));
+    let submitted = Instant::now();
+
+    let mut last = JobState::Queued;
+    loop {
+      tokio::select! {
+        () = tokio::time::sleep(POLL_INTERVAL) => {}
+        () = token.cancelled() => {
+          api.cancel(&job_id).await?;
+          return Ok(JobState::Cancelled);
+        }
+      }
+      let (state, progress) = api.status(&job_id).await?;
+      if let Some(progress) = progress {
+        self.events(progress.elapsed, progress.events());
+      }
+      if state.is_done() {
+        if state == JobState::Finished {
+          let result = api.result(&job_id).await?;
+          result
+            .log
+            .lines()
+            .for_each(|line| self.log(line.to_string()));
+          let events = parser::parse_log(spec.solver, &result.log);
+          self.events(submitted.elapsed(), events);
+          let objective = result
+            .objective
+            .map_or(String::from("none"), |o| o.to_string());
+          self.log(format!("{}, objective {objective}", result.status));
+          let solution = Solution {
+            status: result.status,
+            objective: result.objective.map(Objective),
+          };
+          self.send(
+            self.reply_to.clone(),
+            Payload::JobSolution(self.spec.id, solution),
+          );
+        }
+        return Ok(state);
+      }
+      if state != last {
+        self.state(state);
+        last = state;
+      }
+    }
+  }
+
+  /// Reports a state change.
+  fn state(&self, state: JobState) {
+    self.send(
+      self.reply_to.clone(),
+      Payload::JobState(self.spec.id, state),
+    );
+  }
+
+  /// Reports events read from the solver's progress or output.
+  fn events(&self, elapsed: Duration, events: Vec<SolverEvent>) {
+    if !events.is_empty() {
+      self.send(
+        self.reply_to.clone(),
+        Payload::JobEvents(self.spec.id, elapsed, events),
+      );
+    }
+  }
+
+  /// Reports a line of output on the job's log stream.
+  fn log(&self, line: String) {
+    let destination = Address::Job(self.spec.id);
+    self.send(destination, Payload::JobLog(self.spec.id, line));
+  }
+
+  fn send(&self, destination: Address, payload: Payload) {
+    self
+      .tx
+      .send(Message {
+        source: Address::IrxClient,
+        destination,
+        payload,
+        tag: None,
+        cacheable: Cacheable::No,
+        kind: Kind::Tell,
+      })
+      .ok();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::irx_client::{
+    http::{Http, HttpPolicy},
+    stub::{key, stub},
+    usage::Usage,
+  };
+  use crate::job::JobId;
+  use pretty_assertions::assert_eq;
+  use std::sync::Arc;
+
+  #[tokio::test]
+  async fn test_jobs_api() -> Result<()> {
+    let (base, handle) = stub(vec![
+      r#"{"jobId":"j-1"}"#,
+      r#"{"state":"running","progress":{"elapsed":1.5,"dualBound":-470}}"#,
+      "{}",
+      r#"{"status":"optimal","objective":-464.75,"log":"done"}"#,
+    ])
+    .await;
+    let usage = Arc::new(Usage::default());
+    let api = JobsApi::new(ApiClient::new(
+      Http::new(HttpPolicy::default())?,
+      base,
+      key(),
+      usage.clone(),
+    ));
+    let id = api
+      .submit(
+        "afiro",
+        Solver::Highs,
+        &SolverParams::default(),
+        String::new(),
+      )
+      .await?;
+    assert_eq!(id, "j-1");
+    let (state, progress) = api.status(&id).await?;
+    assert_eq!(state, JobState::Running);
+    let progress = progress.expect("the job reports progress");
+    assert_eq!(progress.elapsed, Duration::from_millis(1500));
+    assert_eq!(progress.primal, None);
+    assert_eq!(progress.dual, Some(Objective(-470.0)));
+    api.cancel(&id).await?;
+    let result = api.result(&id).await?;
+    assert_eq!(result.objective, Some(-464.75));
+    let requests = handle.await?;
+    assert_eq!(requests[0], "POST /jobs HTTP/1.1");
+    assert_eq!(requests[1], format!("x-api-key: {}", key().expose()));
+    assert_eq!(requests[2], "GET /jobs/j-1 HTTP/1.1");
+    assert_eq!(requests[4], "POST /jobs/j-1/cancel HTTP/1.1");
+    assert_eq!(requests[6], "GET /jobs/j-1/result HTTP/1.1");
+    assert_eq!(usage.session(), 4);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_remote_job_missing_problem() -> Result<()> {
+    let (base, _) = stub(vec![]).await;
+    let http = Http::new(HttpPolicy::default())?;
+    let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+    let job = RemoteJob {
+      spec: JobSpec {
+        id: JobId(1),
+        problem: "/nonexistent/problem.mps".into(),
+        solver: Solver::Highs,
+        ..JobSpec::default()
+      },
+      reply_to: Address::Session,
+      tx,
+    };
+    job
+      .execute(
+        JobsApi::new(ApiClient::new(http, base, key(), Arc::default())),
+        CancellationToken::new(),
+      )
+      .await;
+    let log = rx.recv().await.map(|m| m.payload);
+    assert!(matches!(log, Some(Payload::JobLog(JobId(1), _))));
+    let state = rx.recv().await.map(|m| m.payload);
+    assert_eq!(state, Some(Payload::JobState(JobId(1), JobState::Failed)));
+    Ok(())
+  }
+}
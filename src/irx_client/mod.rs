@@ -1,22 +1,61 @@
-use crate::irx_client::api::ApiKey;
+use crate::job::JobId;
 use crate::router::{Address, Cacheable, Kind, Message, Payload};
-use color_eyre::eyre::{eyre, Result};
+use color_eyre::eyre::Result;
 use email_address::EmailAddress;
-use serde::Deserialize;
-use std::{collections::HashMap, fs, str::FromStr};
+use std::{collections::HashMap, sync::Arc};
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use tracing::{self, instrument};
 use url::Url;
+mod account;
 pub mod api;
+mod credentials;
+mod http;
+mod jobs;
+mod registrar;
+mod settings;
+#[cfg(test)]
+mod stub;
+mod usage;
+pub use account::Account;
+use account::AccountApi;
+use api::ApiClient;
+use credentials::CredentialStore;
+pub use credentials::PASSPHRASE_ENV;
+pub use http::Http;
+use jobs::{JobsApi, RemoteJob};
+pub use registrar::{HttpRegistrar, Registrar, REGISTRATION_KEY_ENV};
+pub use settings::{
+  Connectivity, KeySource, KeyStatus, Registration, Settings, KEY_ENV,
+};
+use usage::Usage;
 
 /// A client for interacting with the IRX API.
 ///
 /// This structure manages the API key and handles message routing
-/// between the client and the router.
+/// between the client and the router. Jobs submitted to it are solved
+/// remotely and polled until they stop.
+///
+/// The API is only contacted in the background once the responder runs,
+/// so that startup never waits on the network. Until it answers, or if it
+/// can't be reached, remote features are unavailable.
+///
+/// Every request made to the API is counted, and the counts are reported
+/// along with the key's tier when asked about the account. Requests share
+/// one `Http` layer, which reuses connections and retries transient
+/// failures; failures reach the UI as `ApiError` messages.
 #[derive(Debug)]
 pub struct IrxClient {
-  /// Optional API key for the client.
-  pub api_key: Option<ApiKey>,
+  /// The API key, where it came from, and whether the API is reachable.
+  status: KeyStatus,
+  /// The resolved endpoint and key file.
+  settings: Settings,
+  /// Requests made to the API.
+  usage: Arc<Usage>,
+  /// The HTTP layer requests are sent through.
+  http: Http,
+  /// Registers new keys.
+  registrar: Arc<dyn Registrar>,
   /// Cloned sender for routing messages to the Router.
   message_tx_to_router: mpsc::UnboundedSender<Message>,
   /// Unique receiver for messages from the Router.
@@ -25,58 +64,75 @@ pub struct IrxClient {
   pub message_tx_to_self: mpsc::UnboundedSender<Message>,
 }
 
-/// Response body structure received after registration.
-#[derive(Deserialize, Debug)]
-struct RegistrationResponseBody {
-  #[serde(rename = "apiKeyId")]
-  _api_key_id: String,
-  #[serde(rename = "apiKeyValue")]
-  api_key_value: ApiKey,
-}
-
-/// Structure representing the response from a registration request.
-#[derive(Deserialize, Debug)]
-struct RegistrationResponse {
-  #[serde(rename = "statusCode")]
-  _status_code: u32,
-  body: String,
-}
-
 impl IrxClient {
-  /// Base URL for the IRX API.
-  const BASE: &str = "https://api.irx.sh/";
-
   /// Creates a new instance of `IrxClient`.
   ///
-  /// This asynchronous function initializes the client, setting up the
-  /// API key and message channels.
+  /// This function initializes the client, setting up the API key and
+  /// message channels. The key is taken from `$IRX_API_KEY` or the chosen
+  /// profile in the key file; if neither has one, a new key is registered
+  /// once the responder runs. A key that's stored but can't be decrypted is
+  /// left alone rather than replaced. If the proxy or CA certificates can't
+  /// be used, the API is never contacted, and the status says why.
   ///
   /// # Arguments
   ///
   /// * `tx` - UnboundedSender for sending messages to the Router.
+  /// * `settings` - The resolved endpoint and credential locations.
+  /// * `http` - The HTTP layer requests are sent through.
+  /// * `registrar` - Registers new keys.
   ///
   /// # Returns
   ///
   /// A result containing the new `IrxClient` instance or an error.
   #[instrument]
-  pub async fn new(tx: mpsc::UnboundedSender<Message>) -> Result<Self> {
-    let api_key = match Self::read_api_key_from_config() {
-      Some(key) => Some(key),
-      None => match Self::request_new_api_key(None).await {
-        Ok(key) => {
-          Self::write_api_key_to_config(&key)?;
-          Some(key)
-        }
-        Err(e) => {
-          log::warn!("{e:?}");
-          None
-        }
+  pub fn new(
+    tx: mpsc::UnboundedSender<Message>,
+    settings: Settings,
+    http: Http,
+    registrar: Arc<dyn Registrar>,
+  ) -> Result<Self> {
+    let credentials = settings.credentials();
+    let mut warning = credentials.check_permissions();
+    let (key, source) = match (settings.env_key.clone(), credentials.read()) {
+      (Some(key), _) => (Some(key), KeySource::Env),
+      (None, Ok(Some(key))) => (Some(key), KeySource::File),
+      (None, Ok(None)) => (None, KeySource::Missing),
+      (None, Err(e)) => {
+        warning = Some(e.to_string());
+        (None, KeySource::Locked)
+      }
+    };
+    // Keys ignored while resolving the settings are reported alongside
+    let mut warnings = settings.key_warnings.clone();
+    warnings.extend(warning);
+    for warning in &warnings {
+      log::warn!("{warning}");
+    }
+    let status = KeyStatus {
+      key,
+      source,
+      key_file: settings.key_file.clone(),
+      profile: settings.profile.clone(),
+      warning: (!warnings.is_empty()).then(|| warnings.join("; ")),
+      network: http.problem().map(str::to_string),
+      endpoint: settings.endpoint.to_string(),
+      connectivity: if settings.offline {
+        Connectivity::Disabled
+      } else if http.problem().is_some() {
+        Connectivity::Offline
+      } else {
+        Connectivity::Checking
       },
     };
     let (message_tx_to_self, message_rx_from_router) =
       mpsc::unbounded_channel::<Message>();
+    let usage = Usage::load(&crate::utils::get_data_dir().join("requests"));
     Ok(Self {
-      api_key,
+      status,
+      settings,
+      usage: Arc::new(usage),
+      http,
+      registrar,
       message_tx_to_router: tx,
       message_rx_from_router: Some(message_rx_from_router),
       message_tx_to_self,
@@ -86,146 +142,503 @@ impl IrxClient {
   /// Runs the message responder within the client.
   ///
   /// Listen for incoming messages from the router and
-  /// handle them based on their kind.
+  /// handle them based on their kind. Unless offline mode is on or the
+  /// network settings are broken, the API is contacted in the background,
+  /// and a key is registered if none was found.
+  ///
+  /// Questions carrying `Payload::Account` are answered with the account;
+  /// any other question is answered with the key status.
   pub fn run_responder(&mut self) {
     let mut message_rx_from_router = self
       .message_rx_from_router
       .take()
       .expect("receiver is not None");
     let tx = self.message_tx_to_router.clone();
-    let key = self.api_key.clone();
+    let mut status = self.status.clone();
+    let mut account = Account::default();
+    let base = self.settings.endpoint.clone();
+    let usage = self.usage.clone();
+    let http = self.http.clone();
+    let credentials = self.settings.credentials();
+    let registrar = self.registrar.clone();
+
+    if status.connectivity != Connectivity::Checking {
+      Self::announce(&tx, status.connectivity);
+    } else {
+      tokio::spawn(Self::provision(
+        status.clone(),
+        credentials.clone(),
+        registrar.clone(),
+        http.clone(),
+        base.clone(),
+        usage.clone(),
+        tx.clone(),
+      ));
+    }
 
     tokio::spawn(async move {
+      let mut jobs: HashMap<JobId, CancellationToken> = HashMap::new();
       loop {
-        if let Some(message) = message_rx_from_router.recv().await {
-          match message.kind {
-            // TODO HACK make this general
-            Kind::Ask => {
-              let response = Message {
-                source: Address::IrxClient,
-                destination: message.source,
-                payload: (if let Some(k) = key.clone() {
-                  Payload::ApiKey(k.clone())
+        let Some(message) = message_rx_from_router.recv().await else {
+          break;
+        };
+        let from_self = message.source == Address::IrxClient;
+        let api = Self::account_api(&status, &http, &base, &usage);
+        match message.kind {
+          Kind::Ask => {
+            let (payload, cacheable) = match message.payload {
+              Payload::Account(_) => {
+                let account = Account {
+                  session_requests: usage.session(),
+                  lifetime_requests: usage.lifetime(),
+                  ..account.clone()
+                };
+                (Payload::Account(account), Cacheable::No)
+              }
+              _ => (Payload::KeyStatus(status.clone()), Cacheable::Yes),
+            };
+            let response = Message {
+              source: Address::IrxClient,
+              destination: message.source,
+              payload,
+              tag: message.tag,
+              cacheable,
+              kind: Kind::Tell,
+            };
+            tx.send(response).ok();
+          }
+          Kind::Tell => match message.payload {
+            Payload::KeyStatus(provisioned) if from_self => {
+              status = provisioned;
+              Self::announce(&tx, status.connectivity);
+              if let Some(api) =
+                Self::account_api(&status, &http, &base, &usage)
+              {
+                tokio::spawn(Self::refresh_tier(api, tx.clone()));
+              }
+            }
+            Payload::ApiKey(key, source) if from_self => {
+              status.key = key;
+              status.source = source;
+              account.tier = None;
+              if let Some(api) =
+                Self::account_api(&status, &http, &base, &usage)
+              {
+                tokio::spawn(Self::refresh_tier(api, tx.clone()));
+              }
+            }
+            Payload::Account(update) if from_self => {
+              if update.tier.is_some() {
+                account.tier = update.tier;
+              }
+              if update.notice.is_some() {
+                account.notice = update.notice;
+              }
+            }
+            Payload::RegenerateKey | Payload::RevokeKey if api.is_none() => {
+              account.notice = Some(format!(
+                "can't change the key: {}",
+                if status.key.is_none() {
+                  String::from("there is none")
                 } else {
-                  Payload::Empty
-                }),
-                tag: None,
-                cacheable: Cacheable::No,
-                kind: Kind::Tell,
-              };
-              tx.send(response).ok();
+                  format!("the API is {}", status.connectivity)
+                }
+              ));
+            }
+            Payload::RegenerateKey => {
+              if let Some(api) = api {
+                tokio::spawn(Self::regenerate(
+                  api,
+                  status.clone(),
+                  credentials.clone(),
+                  tx.clone(),
+                ));
+              }
+            }
+            Payload::RevokeKey => {
+              if let Some(api) = api {
+                tokio::spawn(Self::revoke(
+                  api,
+                  status.clone(),
+                  credentials.clone(),
+                  tx.clone(),
+                ));
+              }
+            }
+            Payload::Email(email) if !status.connectivity.is_online() => {
+              let reason = format!("the API is {}", status.connectivity);
+              log::warn!("can't register {email}: {reason}");
+              let registration = Registration::Failed(reason);
+              Self::report(&tx, message.source, registration);
             }
-            Kind::Tell => {
-              if let Payload::Email(email) = message.payload {
-                let _upgraded_key = // TODO use the new key
-                  Self::request_new_api_key(Some(email)).await.unwrap(); // HACK don't unwrap
+            Payload::Email(email) => {
+              tokio::spawn(Self::upgrade(
+                email,
+                status.clone(),
+                credentials.clone(),
+                registrar.clone(),
+                usage.clone(),
+                message.source,
+                tx.clone(),
+              ));
+            }
+            Payload::SubmitJob(spec) => {
+              let job = RemoteJob {
+                spec,
+                reply_to: message.source,
+                tx: tx.clone(),
+              };
+              if !status.connectivity.is_online() {
+                job.reject(&format!(
+                  "remote solving is unavailable: the API is {}",
+                  status.connectivity
+                ));
+                continue;
+              }
+              let Some(k) = status.key.clone() else {
+                job.reject("no API key; register from the Home scene");
+                continue;
               };
+              let token = CancellationToken::new();
+              jobs.retain(|_, token| !token.is_cancelled());
+              jobs.insert(job.spec.id, token.clone());
+              let client =
+                ApiClient::new(http.clone(), base.clone(), k, usage.clone());
+              let api = JobsApi::new(client);
+              tokio::spawn(job.execute(api, token));
             }
-          }
+            Payload::CancelJob(id) => {
+              if let Some(token) = jobs.remove(&id) {
+                token.cancel();
+              }
+            }
+            _ => {}
+          },
         }
       }
     });
   }
 
-  /// Reads the API key from the configuration file.
-  ///
-  /// This function attempts to retrieve the API key from the local
-  /// configuration file, returning it if found.
-  ///
-  /// # Returns
+  /// Returns a client for the account endpoints, if they can be used.
   ///
-  /// An option containing the `ApiKey` if found, or `None` otherwise.
-  #[instrument]
-  fn read_api_key_from_config() -> Option<ApiKey> {
-    Self::get_config_path()
-      .and_then(|path| fs::read_to_string(path.join("key.txt")).ok())
-      .map(|s| s.replace('\n', ""))
-      .and_then(|s| s.parse().ok())
-      .or_else(|| {
-        tracing::info!("api key not found on disk");
-        None
-      })
+  /// Only remote features need the API, and they all need a key.
+  fn account_api(
+    status: &KeyStatus,
+    http: &Http,
+    base: &Url,
+    usage: &Arc<Usage>,
+  ) -> Option<AccountApi> {
+    match (&status.key, status.connectivity.is_online()) {
+      (Some(key), true) => Some(AccountApi::new(ApiClient::new(
+        http.clone(),
+        base.clone(),
+        key.clone(),
+        usage.clone(),
+      ))),
+      _ => None,
+    }
   }
 
-  /// Retrieves the configuration path for the client.
+  /// Sends a message to the responder itself through the router.
   ///
-  /// This function computes the path to the configuration directory
-  /// where the API key is stored. It typically points to a `.config/irx`
-  /// directory in the user's home folder.
+  /// It's marked cacheable so that the router forgets the status it cached
+  /// before.
+  fn tell_self(tx: &mpsc::UnboundedSender<Message>, payload: Payload) {
+    tx.send(Message {
+      source: Address::IrxClient,
+      destination: Address::IrxClient,
+      payload,
+      tag: None,
+      cacheable: Cacheable::Yes,
+      kind: Kind::Tell,
+    })
+    .ok();
+  }
+
+  /// Fetches the key's tier and sends it back to the responder.
+  async fn refresh_tier(api: AccountApi, tx: mpsc::UnboundedSender<Message>) {
+    match api.tier().await {
+      Ok(tier) => {
+        let account = Account {
+          tier: Some(tier),
+          ..Account::default()
+        };
+        Self::tell_self(&tx, Payload::Account(account));
+      }
+      Err(e) => log::warn!("failed to fetch the account: {e:?}"),
+    }
+  }
+
+  /// Replaces the key with a new one, and saves it to the key file.
   ///
-  /// # Returns
+  /// # Arguments
   ///
-  /// An option containing the `PathBuf` to the configuration directory
-  /// if the home directory can be determined, or `None` otherwise.
-  fn get_config_path() -> Option<std::path::PathBuf> {
-    dirs::home_dir().map(|home| home.join(".config").join("irx"))
+  /// * `api` - Client for the account endpoints, using the current key.
+  /// * `status` - The client's status when the request arrived.
+  /// * `credentials` - Where the key is stored.
+  /// * `tx` - Sender for messages to the Router.
+  async fn regenerate(
+    api: AccountApi,
+    status: KeyStatus,
+    credentials: CredentialStore,
+    tx: mpsc::UnboundedSender<Message>,
+  ) {
+    let notice = match api.regenerate().await {
+      Ok(key) => {
+        if let Err(e) = credentials.write(&key) {
+          log::warn!("{e:?}");
+        }
+        if status.source == KeySource::Env {
+          log::warn!("${KEY_ENV} will override the new key next time");
+        }
+        let payload = Payload::ApiKey(Some(key), KeySource::Regenerated);
+        Self::tell_self(&tx, payload);
+        String::from("regenerated the key")
+      }
+      Err(e) => format!("failed to regenerate the key: {e}"),
+    };
+    Self::notify(&tx, notice);
   }
 
-  /// Writes the API key to the configuration file.
-  ///
-  /// This function saves the given API key to a file named `key.txt` within
-  /// the configuration directory.
+  /// Revokes the key, and removes it from the key file.
   ///
   /// # Arguments
   ///
-  /// * `api_key` - A reference to the `ApiKey` to be saved.
+  /// * `api` - Client for the account endpoints, using the current key.
+  /// * `status` - The client's status when the request arrived.
+  /// * `credentials` - Where the key is stored.
+  /// * `tx` - Sender for messages to the Router.
+  async fn revoke(
+    api: AccountApi,
+    status: KeyStatus,
+    credentials: CredentialStore,
+    tx: mpsc::UnboundedSender<Message>,
+  ) {
+    let notice = match api.revoke().await {
+      Ok(()) => {
+        if status.source != KeySource::Env {
+          if let Err(e) = credentials.remove() {
+            log::warn!("failed to remove the key: {e:?}");
+          }
+        }
+        Self::tell_self(&tx, Payload::ApiKey(None, KeySource::Missing));
+        if status.source == KeySource::Env {
+          format!("revoked the key; unset ${KEY_ENV} too")
+        } else {
+          String::from("revoked the key")
+        }
+      }
+      Err(e) => format!("failed to revoke the key: {e}"),
+    };
+    Self::notify(&tx, notice);
+  }
+
+  /// Sends a notice about the account back to the responder.
+  fn notify(tx: &mpsc::UnboundedSender<Message>, notice: String) {
+    log::info!("{notice}");
+    let account = Account {
+      notice: Some(notice),
+      ..Account::default()
+    };
+    Self::tell_self(tx, Payload::Account(account));
+  }
+
+  /// Checks that the API is reachable and registers a key if needed.
   ///
-  /// # Returns
+  /// The updated status is sent back to the responder through the router,
+  /// marked cacheable so that the router forgets the status it cached
+  /// before. No key is registered over one that's stored but locked.
   ///
-  /// A result indicating the success or failure of the operation.
-  #[instrument]
-  fn write_api_key_to_config(api_key: &ApiKey) -> Result<()> {
-    let config_path = Self::get_config_path()
-      .ok_or_else(|| eyre!("failed to get config path"))?;
-    let key_path = config_path.as_path().join(std::path::Path::new("key.txt"));
-    if let Err(e) = fs::create_dir_all(config_path) {
-      log::warn!("{e:?}");
+  /// # Arguments
+  ///
+  /// * `status` - The status before contacting the API.
+  /// * `credentials` - Where the key is stored.
+  /// * `registrar` - Registers a key if there's none.
+  /// * `http` - The HTTP layer requests are sent through.
+  /// * `base` - Base URL of the API.
+  /// * `usage` - Counter for requests made to the API.
+  /// * `tx` - Sender for messages to the Router.
+  async fn provision(
+    mut status: KeyStatus,
+    credentials: CredentialStore,
+    registrar: Arc<dyn Registrar>,
+    http: Http,
+    base: Url,
+    usage: Arc<Usage>,
+    tx: mpsc::UnboundedSender<Message>,
+  ) {
+    status.connectivity = if Self::is_reachable(&http, &base, &usage).await {
+      Connectivity::Online
     } else {
-      fs::write(key_path, api_key.to_string())?;
+      log::warn!("{base} is unreachable; remote features are disabled");
+      Connectivity::Offline
+    };
+    if status.connectivity.is_online()
+      && status.key.is_none()
+      && status.source != KeySource::Locked
+    {
+      usage.record();
+      match registrar.register(None).await {
+        Ok(key) => {
+          if let Err(e) = credentials.write(&key) {
+            log::warn!("{e:?}");
+          }
+          status.key = Some(key);
+          status.source = KeySource::Registered;
+        }
+        Err(e) => log::warn!("{e:?}"),
+      }
     }
-    Ok(())
+    Self::tell_self(&tx, Payload::KeyStatus(status));
   }
 
-  /// Requests a new API key from the IRX service.
+  /// Registers an email address to upgrade the API key.
   ///
-  /// This asynchronous function makes a request to the IRX API to obtain
-  /// a new API key. An optional email address can be provided to associate
-  /// with the API key.
+  /// The upgraded key is written to the key file and sent back to the
+  /// responder through the router, so that it's used from now on and the
+  /// router forgets the status it cached before. How it went is reported to
+  /// whoever sent the address.
   ///
   /// # Arguments
   ///
-  /// * `email` - An optional `EmailAddress` to be associated with the new API key.
-  ///
-  /// # Returns
-  ///
-  /// A result containing the new `ApiKey` or an error.
-  #[instrument]
-  async fn request_new_api_key(email: Option<EmailAddress>) -> Result<ApiKey> {
-    let registration_key =
-      ApiKey::from_str("ZtXHo0GHBX4PoDdHd2Gn27rsxGLoFVe086W7Zchk")
-        .map_err(|e| eyre!(e))?;
-
-    let client = reqwest::Client::new();
-    let registration_url = Url::parse(Self::BASE)
-      .expect("base url is valid")
-      .join("register")
-      .expect("registration url is valid");
-
-    let request_base = client
-      .post(registration_url)
-      .header("x-api-key", registration_key.to_string());
-    let request = match email {
-      Some(address) => {
-        let mut map = HashMap::new();
-        map.insert("email", address.clone());
-        request_base.json(&map)
+  /// * `email` - The address to register.
+  /// * `status` - The client's status when the address arrived.
+  /// * `credentials` - Where the key is stored.
+  /// * `registrar` - Registers the upgraded key.
+  /// * `usage` - Counter for requests made to the API.
+  /// * `reply_to` - Where to report how it went.
+  /// * `tx` - Sender for messages to the Router.
+  async fn upgrade(
+    email: EmailAddress,
+    status: KeyStatus,
+    credentials: CredentialStore,
+    registrar: Arc<dyn Registrar>,
+    usage: Arc<Usage>,
+    reply_to: Address,
+    tx: mpsc::UnboundedSender<Message>,
+  ) {
+    usage.record();
+    let registration = match registrar.register(Some(email.clone())).await {
+      Ok(key) => {
+        if let Err(e) = credentials.write(&key) {
+          log::warn!("{e:?}");
+        }
+        if status.source == KeySource::Env {
+          log::warn!("${KEY_ENV} will override the upgraded key next time");
+        }
+        Self::tell_self(&tx, Payload::ApiKey(Some(key), KeySource::Upgraded));
+        Registration::Registered(email)
+      }
+      Err(e) => {
+        log::warn!("failed to register {email}: {e:?}");
+        Registration::Failed(e.to_string())
       }
-      None => request_base,
     };
-    let registration_response: RegistrationResponse =
-      request.send().await?.json::<RegistrationResponse>().await?;
-    let body: RegistrationResponseBody =
-      serde_json::from_str(&registration_response.body)?;
-    Ok(body.api_key_value)
+    Self::report(&tx, reply_to, registration);
+  }
+
+  /// Reports how registering an email address went.
+  fn report(
+    tx: &mpsc::UnboundedSender<Message>,
+    destination: Address,
+    registration: Registration,
+  ) {
+    tx.send(Message {
+      source: Address::IrxClient,
+      destination,
+      payload: Payload::Registration(registration),
+      tag: None,
+      cacheable: Cacheable::No,
+      kind: Kind::Tell,
+    })
+    .ok();
+  }
+
+  /// Returns whether the API answers at all within the timeout.
+  ///
+  /// Any answer will do, so the request isn't retried.
+  async fn is_reachable(http: &Http, base: &Url, usage: &Usage) -> bool {
+    usage.record();
+    http.client().head(base.clone()).send().await.is_ok()
+  }
+
+  /// Tells the components that offer remote features whether they can.
+  fn announce(tx: &mpsc::UnboundedSender<Message>, connectivity: Connectivity) {
+    for destination in [Address::Session, Address::Home] {
+      tx.send(Message {
+        source: Address::IrxClient,
+        destination,
+        payload: Payload::Connectivity(connectivity),
+        tag: None,
+        cacheable: Cacheable::No,
+        kind: Kind::Tell,
+      })
+      .ok();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use credentials::DEFAULT_PROFILE;
+  use pretty_assertions::assert_eq;
+  use stub::{key, stub, FakeRegistrar};
+
+  async fn provision(
+    source: KeySource,
+    registrar: FakeRegistrar,
+    credentials: &CredentialStore,
+  ) -> KeyStatus {
+    let (base, _) = stub(vec![""]).await;
+    let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+    let status = KeyStatus {
+      source,
+      ..KeyStatus::default()
+    };
+    let usage = Arc::new(Usage::default());
+    IrxClient::provision(
+      status,
+      credentials.clone(),
+      Arc::new(registrar),
+      Http::new(http::HttpPolicy::default()).unwrap(),
+      base,
+      usage,
+      tx,
+    )
+    .await;
+    match rx.recv().await.map(|message| message.payload) {
+      Some(Payload::KeyStatus(status)) => status,
+      payload => panic!("unexpected {payload:?}"),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_provision_registers() -> Result<()> {
+    let dir = std::env::temp_dir()
+      .join(format!("napali-provision-{}", std::process::id()));
+    let credentials = CredentialStore::new(
+      dir.join("key.txt"),
+      String::from(DEFAULT_PROFILE),
+      None,
+    );
+
+    let refused = FakeRegistrar(None);
+    let status = provision(KeySource::Missing, refused, &credentials).await;
+    assert_eq!(status.connectivity, Connectivity::Online);
+    assert_eq!((status.key, status.source), (None, KeySource::Missing));
+
+    let locked = FakeRegistrar(Some(key()));
+    let status = provision(KeySource::Locked, locked, &credentials).await;
+    assert_eq!((status.key, status.source), (None, KeySource::Locked));
+    assert_eq!(credentials.read()?, None);
+
+    let registrar = FakeRegistrar(Some(key()));
+    let status = provision(KeySource::Missing, registrar, &credentials).await;
+    assert_eq!(status.key, Some(key()));
+    assert_eq!(status.source, KeySource::Registered);
+    assert_eq!(credentials.read()?, Some(key()));
+    std::fs::remove_dir_all(dir).ok();
+    Ok(())
   }
 }
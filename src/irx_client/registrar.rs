@@ -0,0 +1,160 @@
+use super::api::{self, ApiKey, Register, RegisterRequest};
+use super::http::Http;
+use color_eyre::eyre::{eyre, Result};
+use email_address::EmailAddress;
+use futures::future::BoxFuture;
+use std::{fmt, str::FromStr};
+use url::Url;
+
+/// Environment variable holding the key that authorizes registrations.
+///
+/// It's read at runtime, and failing that, when Napali is built.
+pub const REGISTRATION_KEY_ENV: &str = "IRX_REGISTRATION_KEY";
+
+/// Hands out new API keys.
+///
+/// The client registers a key through this when it has none, and upgrades
+/// its key when an email address is registered.
+pub trait Registrar: fmt::Debug + Send + Sync {
+  /// Registers a new API key.
+  ///
+  /// # Arguments
+  ///
+  /// * `email` - An optional `EmailAddress` to be associated with the new API key.
+  ///
+  /// # Returns
+  ///
+  /// A future resolving to the new `ApiKey` or an error.
+  fn register(
+    &self,
+    email: Option<EmailAddress>,
+  ) -> BoxFuture<'_, Result<ApiKey>>;
+}
+
+/// Registers keys with the IRX API's `register` endpoint.
+#[derive(Debug, Clone)]
+pub struct HttpRegistrar {
+  /// The HTTP layer requests are sent through.
+  http: Http,
+  /// Base URL of the API.
+  base: Url,
+  /// Key that authorizes registrations, if one was provided.
+  registration_key: Option<ApiKey>,
+}
+
+impl HttpRegistrar {
+  /// Creates a registrar for the API rooted at `base`.
+  ///
+  /// # Arguments
+  ///
+  /// * `http` - The HTTP layer requests are sent through.
+  /// * `base` - Base URL of the API.
+  /// * `registration_key` - Key that authorizes registrations. Without
+  ///   one, registering fails.
+  pub fn new(http: Http, base: Url, registration_key: Option<ApiKey>) -> Self {
+    Self {
+      http,
+      base,
+      registration_key,
+    }
+  }
+
+  /// Resolves the key that authorizes registrations.
+  ///
+  /// The key is taken from the first of these that sets it: the
+  /// environment at runtime, the config file, and the environment when
+  /// Napali was built.
+  ///
+  /// # Arguments
+  ///
+  /// * `env` - Value of the `IRX_REGISTRATION_KEY` environment variable.
+  /// * `config` - `irx.registration_key` from the config file.
+  ///
+  /// # Returns
+  ///
+  /// The key, if any was provided, or an error if it's invalid.
+  pub fn resolve_key(
+    env: Option<String>,
+    config: Option<&str>,
+  ) -> Result<Option<ApiKey>> {
+    env
+      .as_deref()
+      .or(config)
+      .or(option_env!("IRX_REGISTRATION_KEY"))
+      .filter(|key| !key.is_empty())
+      .map(ApiKey::from_str)
+      .transpose()
+      .map_err(|e| eyre!("invalid registration key: {e}"))
+  }
+
+  /// Posts a registration request.
+  async fn post(&self, email: Option<EmailAddress>) -> Result<ApiKey> {
+    let registration_key = self.registration_key.as_ref().ok_or_else(|| {
+      eyre!("no registration key; set ${REGISTRATION_KEY_ENV}")
+    })?;
+    let register = Register {
+      body: email.map(|address| RegisterRequest {
+        email: Some(address.to_string()),
+      }),
+    };
+    let response =
+      api::call(&self.http, &self.base, registration_key, &register).await?;
+    Ok(response.body.0.api_key_value)
+  }
+}
+
+impl Registrar for HttpRegistrar {
+  fn register(
+    &self,
+    email: Option<EmailAddress>,
+  ) -> BoxFuture<'_, Result<ApiKey>> {
+    Box::pin(self.post(email))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::irx_client::{
+    http::HttpPolicy,
+    stub::{key, stub},
+  };
+  use pretty_assertions::assert_eq;
+
+  #[tokio::test]
+  async fn test_http_registrar() -> Result<()> {
+    let (base, handle) = stub(vec![
+      r#"{"statusCode":200,"body":"{\"apiKeyId\":\"id\",\"apiKeyValue\":\"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"}"}"#,
+    ])
+    .await;
+    let http = Http::new(HttpPolicy::default())?;
+    let registrar = HttpRegistrar::new(http, base, Some(key()));
+    assert_eq!(registrar.register(None).await?.expose(), "a".repeat(40));
+    let requests = handle.await?;
+    assert_eq!(requests[0], "POST /register HTTP/1.1");
+    assert_eq!(requests[1], format!("x-api-key: {}", key().expose()));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_http_registrar_without_key() {
+    let base = Url::parse("http://127.0.0.1:9/").unwrap();
+    let http = Http::new(HttpPolicy::default()).unwrap();
+    let error = HttpRegistrar::new(http, base, None)
+      .register(None)
+      .await
+      .unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "no registration key; set $IRX_REGISTRATION_KEY"
+    );
+  }
+
+  #[test]
+  fn test_resolve_key() -> Result<()> {
+    let env = Some(key().expose().to_string());
+    assert_eq!(HttpRegistrar::resolve_key(env, Some("short"))?, Some(key()));
+    assert!(HttpRegistrar::resolve_key(None, Some("short")).is_err());
+    Ok(())
+  }
+}
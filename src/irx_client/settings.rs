@@ -0,0 +1,382 @@
+use super::api::ApiKey;
+use super::credentials::{
+  CredentialStore, Passphrase, DEFAULT_PROFILE, PASSPHRASE_ENV,
+};
+use super::http::HttpPolicy;
+use crate::config::IrxConfig;
+use color_eyre::eyre::{eyre, Result};
+use email_address::EmailAddress;
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::PathBuf, str::FromStr};
+use url::Url;
+
+/// Environment variable that overrides the API key stored on disk.
+pub const KEY_ENV: &str = "IRX_API_KEY";
+
+/// Default base URL of the IRX API.
+const DEFAULT_ENDPOINT: &str = "https://api.irx.sh/";
+
+/// Where the client's API key came from.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum KeySource {
+  /// No key was found, and none could be registered.
+  #[default]
+  Missing,
+  /// The key was read from the `IRX_API_KEY` environment variable.
+  Env,
+  /// The key was read from the key file.
+  File,
+  /// A new key was registered and written to the key file.
+  Registered,
+  /// The key was upgraded by registering an email address.
+  Upgraded,
+  /// The key was replaced with a new one from the account panel.
+  Regenerated,
+  /// A key is stored, but it couldn't be read or decrypted.
+  Locked,
+}
+
+impl fmt::Display for KeySource {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      KeySource::Missing => write!(f, "no"),
+      KeySource::Env => write!(f, "yes (${KEY_ENV})"),
+      KeySource::File => write!(f, "yes"),
+      KeySource::Registered => write!(f, "no (registered a new key)"),
+      KeySource::Upgraded => write!(f, "yes (upgraded with your email)"),
+      KeySource::Regenerated => write!(f, "yes (regenerated)"),
+      KeySource::Locked => write!(f, "yes (locked; check ${PASSPHRASE_ENV})"),
+    }
+  }
+}
+
+/// How registering an email address went, as reported to the UI.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Registration {
+  /// The request is on its way.
+  #[default]
+  Pending,
+  /// The address was registered, and the upgraded key is in use.
+  Registered(EmailAddress),
+  /// The address couldn't be registered.
+  Failed(String),
+}
+
+impl fmt::Display for Registration {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Registration::Pending => write!(f, "registering..."),
+      Registration::Registered(email) => write!(f, "registered {email}"),
+      Registration::Failed(reason) => {
+        write!(f, "registration failed: {reason}")
+      }
+    }
+  }
+}
+
+/// Whether the IRX API can be reached.
+#[derive(
+  Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Connectivity {
+  /// The API hasn't been contacted yet.
+  #[default]
+  Checking,
+  /// The API answered.
+  Online,
+  /// The API couldn't be reached.
+  Offline,
+  /// Offline mode was requested, so the API is never contacted.
+  Disabled,
+}
+
+impl Connectivity {
+  /// Returns whether remote features can be used.
+  pub fn is_online(self) -> bool {
+    self == Connectivity::Online
+  }
+}
+
+impl fmt::Display for Connectivity {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Connectivity::Checking => write!(f, "checking"),
+      Connectivity::Online => write!(f, "online"),
+      Connectivity::Offline => write!(f, "offline"),
+      Connectivity::Disabled => write!(f, "offline mode"),
+    }
+  }
+}
+
+/// The resolved endpoint and credential locations of the IRX client.
+///
+/// Each value is taken from the first of these that sets it: the command
+/// line, the environment (API key and passphrase only), the chosen profile's
+/// config (endpoint only), the config file, and the built-in default.
+/// Offline mode is on if either the command line or the config file asks
+/// for it. Timeouts, retries, the proxy and CA certificates only come from
+/// the config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+  /// Base URL of the API, always ending in `/`.
+  pub endpoint: Url,
+  /// File the API keys are read from and registered keys are written to.
+  pub key_file: PathBuf,
+  /// Profile whose key is used.
+  pub profile: String,
+  /// Passphrase that encrypts stored keys.
+  pub passphrase: Option<Passphrase>,
+  /// API key that takes precedence over the key file.
+  pub env_key: Option<ApiKey>,
+  /// Why keys that were given, such as a malformed `$IRX_API_KEY`, are
+  /// ignored.
+  pub key_warnings: Vec<String>,
+  /// Whether the API must never be contacted.
+  pub offline: bool,
+  /// How requests to the API are timed out and retried.
+  pub http: HttpPolicy,
+  /// Proxy that requests to the API go through.
+  pub proxy: Option<String>,
+  /// PEM files of extra CA certificates to trust.
+  pub ca_certs: Vec<PathBuf>,
+}
+
+impl Settings {
+  /// Resolves the client settings.
+  ///
+  /// # Arguments
+  /// - `endpoint`: Endpoint given on the command line.
+  /// - `key_file`: Key file given on the command line.
+  /// - `profile`: Profile given on the command line.
+  /// - `env_key`: Value of the `IRX_API_KEY` environment variable.
+  /// - `passphrase`: Value of the `IRX_KEY_PASSPHRASE` environment variable.
+  /// - `offline`: Whether offline mode was requested on the command line.
+  /// - `config`: The `irx` section of the config file.
+  ///
+  /// # Returns
+  /// The resolved `Settings`, or an error if a configured value is invalid.
+  /// A malformed `$IRX_API_KEY` is ignored rather than rejected, so that
+  /// local features still work, and noted in `key_warnings`.
+  pub fn resolve(
+    endpoint: Option<Url>,
+    key_file: Option<PathBuf>,
+    profile: Option<String>,
+    env_key: Option<String>,
+    passphrase: Option<String>,
+    offline: bool,
+    config: &IrxConfig,
+  ) -> Result<Settings> {
+    let profile = profile
+      .or_else(|| config.profile.clone())
+      .unwrap_or_else(|| String::from(DEFAULT_PROFILE));
+    let configured = config
+      .profiles
+      .get(&profile)
+      .and_then(|p| p.endpoint.as_ref())
+      .or(config.endpoint.as_ref());
+    let endpoint = match (endpoint, configured) {
+      (Some(url), _) => url,
+      (None, Some(url)) => Url::parse(url).map_err(|e| {
+        eyre!("invalid endpoint {url:?} for profile {profile}: {e}")
+      })?,
+      (None, None) => Url::parse(DEFAULT_ENDPOINT)?,
+    };
+    let key_file = key_file
+      .or_else(|| config.key_file.clone())
+      .map(|path| crate::utils::expand_home(&path))
+      .or_else(Self::default_key_file)
+      .ok_or_else(|| eyre!("failed to determine the API key file"))?;
+    let (env_key, key_warnings) = match env_key
+      .filter(|key| !key.is_empty())
+      .map(|key| ApiKey::from_str(&key))
+      .transpose()
+    {
+      Ok(key) => (key, Vec::new()),
+      Err(e) => (None, vec![format!("invalid ${KEY_ENV}: {e}")]),
+    };
+    Ok(Settings {
+      endpoint: Self::with_trailing_slash(endpoint),
+      key_file,
+      profile,
+      passphrase: passphrase.and_then(Passphrase::new),
+      env_key,
+      key_warnings,
+      offline: offline || config.offline,
+      http: HttpPolicy::from_config(&config.http),
+      proxy: config.proxy.clone().filter(|proxy| !proxy.is_empty()),
+      ca_certs: config
+        .ca_certs
+        .iter()
+        .map(|path| crate::utils::expand_home(path))
+        .collect(),
+    })
+  }
+
+  /// Returns the store holding the chosen profile's key.
+  pub fn credentials(&self) -> CredentialStore {
+    CredentialStore::new(
+      self.key_file.clone(),
+      self.profile.clone(),
+      self.passphrase.clone(),
+    )
+  }
+
+  /// Returns the key file used when none is configured.
+  fn default_key_file() -> Option<PathBuf> {
+    dirs::home_dir()
+      .map(|home| home.join(".config").join("irx").join("key.txt"))
+  }
+
+  /// Makes relative paths join onto the endpoint rather than replace its
+  /// last segment.
+  fn with_trailing_slash(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+      let path = format!("{}/", url.path());
+      url.set_path(&path);
+    }
+    url
+  }
+}
+
+/// What the client knows about its credentials, as reported to the UI.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct KeyStatus {
+  /// The API key in use, if any.
+  pub key: Option<ApiKey>,
+  /// Where the key came from.
+  pub source: KeySource,
+  /// The resolved key file.
+  pub key_file: PathBuf,
+  /// The profile whose key is used.
+  pub profile: String,
+  /// A problem with how the key is stored, if any.
+  pub warning: Option<String>,
+  /// A problem with the proxy or CA certificates, if any. While there's
+  /// one, the API isn't contacted.
+  pub network: Option<String>,
+  /// The resolved base URL of the API.
+  pub endpoint: String,
+  /// Whether the API can be reached.
+  pub connectivity: Connectivity,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::{HttpConfig, IrxProfile};
+  use pretty_assertions::assert_eq;
+
+  const KEY: &str = "AbCdEfGhIjKlMnOpQrStUvWxYz0123456789wxyz";
+
+  #[test]
+  fn test_resolve_defaults() -> Result<()> {
+    let settings = Settings::resolve(
+      None,
+      None,
+      None,
+      None,
+      None,
+      false,
+      &IrxConfig::default(),
+    )?;
+    assert_eq!(settings.endpoint.as_str(), DEFAULT_ENDPOINT);
+    assert!(settings.key_file.ends_with(".config/irx/key.txt"));
+    assert_eq!(settings.profile, DEFAULT_PROFILE);
+    assert_eq!(settings.env_key, None);
+    assert_eq!(settings.passphrase, None);
+    assert!(!settings.offline);
+    assert_eq!(settings.http, HttpPolicy::default());
+    Ok(())
+  }
+
+  #[test]
+  fn test_resolve_precedence() -> Result<()> {
+    let config = IrxConfig {
+      endpoint: Some(String::from("https://staging.irx.sh/v1")),
+      key_file: Some(PathBuf::from("/etc/irx/key.txt")),
+      offline: true,
+      http: HttpConfig {
+        timeout_secs: Some(60),
+        ..HttpConfig::default()
+      },
+      ..IrxConfig::default()
+    };
+    let settings =
+      Settings::resolve(None, None, None, None, None, false, &config)?;
+    assert_eq!(settings.http.timeout, std::time::Duration::from_secs(60));
+    assert_eq!(settings.endpoint.as_str(), "https://staging.irx.sh/v1/");
+    assert_eq!(settings.key_file, PathBuf::from("/etc/irx/key.txt"));
+    assert!(settings.offline);
+
+    let endpoint = Url::parse("http://127.0.0.1:8080")?;
+    let key_file = PathBuf::from("key.txt");
+    let settings = Settings::resolve(
+      Some(endpoint),
+      Some(key_file.clone()),
+      None,
+      Some(String::from(KEY)),
+      Some(String::new()),
+      true,
+      &config,
+    )?;
+    assert_eq!(settings.endpoint.as_str(), "http://127.0.0.1:8080/");
+    assert_eq!(settings.key_file, key_file);
+    assert_eq!(settings.env_key, Some(ApiKey::from_str(KEY).unwrap()));
+    assert_eq!(settings.passphrase, None);
+    Ok(())
+  }
+
+  #[test]
+  fn test_resolve_profile() -> Result<()> {
+    let config = IrxConfig {
+      endpoint: Some(String::from("https://api.irx.sh/")),
+      profile: Some(String::from("staging")),
+      profiles: [(
+        String::from("staging"),
+        IrxProfile {
+          endpoint: Some(String::from("https://staging.irx.sh/")),
+        },
+      )]
+      .into(),
+      ..IrxConfig::default()
+    };
+    let settings =
+      Settings::resolve(None, None, None, None, None, false, &config)?;
+    assert_eq!(settings.profile, "staging");
+    assert_eq!(settings.endpoint.as_str(), "https://staging.irx.sh/");
+
+    let profile = Some(String::from("prod"));
+    let settings =
+      Settings::resolve(None, None, profile, None, None, false, &config)?;
+    assert_eq!(settings.profile, "prod");
+    assert_eq!(settings.endpoint.as_str(), "https://api.irx.sh/");
+    Ok(())
+  }
+
+  #[test]
+  fn test_resolve_invalid() {
+    let config = IrxConfig {
+      endpoint: Some(String::from("not a url")),
+      ..IrxConfig::default()
+    };
+    assert!(
+      Settings::resolve(None, None, None, None, None, false, &config).is_err()
+    );
+    let env_key = Some(String::from("short"));
+    let config = IrxConfig::default();
+    let settings =
+      Settings::resolve(None, None, None, env_key, None, false, &config)
+        .expect("a malformed key doesn't stop the client");
+    assert_eq!(settings.env_key, None);
+    assert_eq!(
+      settings.key_warnings,
+      ["invalid $IRX_API_KEY: shorter than 40 characters"]
+    );
+  }
+}
@@ -0,0 +1,76 @@
+//! A stand-in for the IRX API in tests.
+
+use super::{api::ApiKey, Registrar};
+use color_eyre::eyre::{eyre, Result};
+use email_address::EmailAddress;
+use futures::future::BoxFuture;
+use std::str::FromStr;
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpListener,
+  task::JoinHandle,
+};
+use url::Url;
+
+/// Serves one canned JSON body per connection, in order.
+///
+/// # Returns
+/// The stub's base URL, and a handle yielding the request heads it saw.
+pub async fn stub(bodies: Vec<&'static str>) -> (Url, JoinHandle<Vec<String>>) {
+  stub_with_status(bodies.into_iter().map(|body| (200, body)).collect()).await
+}
+
+/// Serves one canned status and JSON body per connection, in order.
+pub async fn stub_with_status(
+  responses: Vec<(u16, &'static str)>,
+) -> (Url, JoinHandle<Vec<String>>) {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let base =
+    Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+  let handle = tokio::spawn(async move {
+    let mut requests = Vec::new();
+    for (status, body) in responses {
+      let (mut socket, _) = listener.accept().await.unwrap();
+      let mut buf = vec![0; 1 << 16];
+      let mut len = 0;
+      while !String::from_utf8_lossy(&buf[..len]).contains("\r\n\r\n") {
+        len += socket.read(&mut buf[len..]).await.unwrap();
+      }
+      let head = String::from_utf8_lossy(&buf[..len]).to_string();
+      requests.push(head.lines().next().unwrap_or_default().to_string());
+      requests.extend(
+        head
+          .lines()
+          .filter(|l| l.to_ascii_lowercase().starts_with("x-api-key"))
+          .map(str::to_string),
+      );
+      let response = format!(
+        "HTTP/1.1 {status} Stub\r\ncontent-type: application/json\r\n\
+         content-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+      );
+      socket.write_all(response.as_bytes()).await.unwrap();
+    }
+    requests
+  });
+  (base, handle)
+}
+
+/// Returns a well-formed API key.
+pub fn key() -> ApiKey {
+  ApiKey::from_str("AbCdEfGhIjKlMnOpQrStUvWxYz0123456789wxyz").unwrap()
+}
+
+/// A registrar that hands out a fixed key, or fails without one.
+#[derive(Debug, Default)]
+pub struct FakeRegistrar(pub Option<ApiKey>);
+
+impl Registrar for FakeRegistrar {
+  fn register(
+    &self,
+    _email: Option<EmailAddress>,
+  ) -> BoxFuture<'_, Result<ApiKey>> {
+    let key = self.0.clone().ok_or_else(|| eyre!("registration refused"));
+    Box::pin(async move { key })
+  }
+}
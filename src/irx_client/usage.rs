@@ -0,0 +1,90 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Counts the requests made to the IRX API.
+///
+/// The session count starts at zero on every launch. The lifetime count is
+/// kept in a small file in the data directory, so it survives restarts; if
+/// that file can't be read or written the count simply starts over.
+#[derive(Debug, Default)]
+pub struct Usage {
+  /// Requests made since Napali started.
+  session: AtomicU64,
+  /// Requests made since the lifetime count was first recorded.
+  lifetime: AtomicU64,
+  /// File the lifetime count is kept in, if any.
+  path: Option<PathBuf>,
+}
+
+impl Usage {
+  /// Loads the lifetime count from a file.
+  ///
+  /// # Arguments
+  ///
+  /// * `path` - File the lifetime count is kept in.
+  ///
+  /// # Returns
+  ///
+  /// A `Usage` with the session count at zero.
+  pub fn load(path: &Path) -> Usage {
+    let lifetime = fs::read_to_string(path)
+      .ok()
+      .and_then(|s| s.trim().parse().ok())
+      .unwrap_or_default();
+    Usage {
+      session: AtomicU64::new(0),
+      lifetime: AtomicU64::new(lifetime),
+      path: Some(path.to_path_buf()),
+    }
+  }
+
+  /// Records a request, and saves the new lifetime count.
+  pub fn record(&self) {
+    self.session.fetch_add(1, Ordering::Relaxed);
+    let lifetime = self.lifetime.fetch_add(1, Ordering::Relaxed) + 1;
+    if let Some(path) = &self.path {
+      let saved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, lifetime.to_string()));
+      if let Err(e) = saved {
+        log::debug!("failed to save request count: {e}");
+      }
+    }
+  }
+
+  /// Returns the number of requests made since Napali started.
+  pub fn session(&self) -> u64 {
+    self.session.load(Ordering::Relaxed)
+  }
+
+  /// Returns the number of requests made overall.
+  pub fn lifetime(&self) -> u64 {
+    self.lifetime.load(Ordering::Relaxed)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_usage_persists_lifetime() {
+    let path = std::env::temp_dir()
+      .join(format!("napali-usage-{}", std::process::id()))
+      .join("requests");
+    let usage = Usage::load(&path);
+    usage.record();
+    usage.record();
+    assert_eq!((usage.session(), usage.lifetime()), (2, 2));
+
+    let usage = Usage::load(&path);
+    usage.record();
+    assert_eq!((usage.session(), usage.lifetime()), (1, 3));
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+  }
+}
@@ -0,0 +1,183 @@
+use super::{Gap, Location, Solver, SolverParams};
+use itertools::iproduct;
+use std::str::FromStr;
+
+/// The values each parameter takes across an ensemble.
+///
+/// Every combination of the values is run. A parameter without values is
+/// left at the solver's default.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid {
+  pub time_limits: Vec<u64>,
+  pub mip_gaps: Vec<Gap>,
+  pub threads: Vec<u32>,
+  pub presolve: Vec<bool>,
+}
+
+impl Grid {
+  /// Parses a comma-separated list of values, e.g. `60, 300`.
+  ///
+  /// # Returns
+  /// The values, or an error naming the first one that doesn't parse.
+  pub fn parse_list<T: FromStr>(text: &str) -> Result<Vec<T>, String> {
+    text
+      .split(',')
+      .map(str::trim)
+      .filter(|value| !value.is_empty())
+      .map(|value| {
+        value
+          .parse()
+          .map_err(|_| format!("invalid value {value:?}"))
+      })
+      .collect()
+  }
+
+  /// Counts the combinations of the values, without listing them.
+  pub fn count(&self) -> usize {
+    [
+      self.time_limits.len(),
+      self.mip_gaps.len(),
+      self.threads.len(),
+      self.presolve.len(),
+    ]
+    .into_iter()
+    .fold(1, |count, len| count.saturating_mul(len.max(1)))
+  }
+
+  /// Returns every combination of the values.
+  pub fn params(&self) -> Vec<SolverParams> {
+    fn values<T: Copy>(list: &[T]) -> Vec<Option<T>> {
+      if list.is_empty() {
+        vec![None]
+      } else {
+        list.iter().copied().map(Some).collect()
+      }
+    }
+    iproduct!(
+      values(&self.time_limits),
+      values(&self.mip_gaps),
+      values(&self.threads),
+      values(&self.presolve)
+    )
+    .map(|(time_limit, mip_gap, threads, presolve)| SolverParams {
+      time_limit,
+      mip_gap,
+      threads,
+      presolve: presolve.unwrap_or(true),
+    })
+    .collect()
+  }
+}
+
+/// One problem run with several solvers and parameter combinations.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ensemble {
+  pub name: String,
+  pub solvers: Vec<Solver>,
+  pub grid: Grid,
+  pub location: Location,
+}
+
+impl Ensemble {
+  /// The most jobs a single ensemble may launch.
+  pub const MAX_JOBS: usize = 64;
+
+  /// Returns the solver and parameters of each job, grouped by solver.
+  pub fn runs(&self) -> Vec<(Solver, SolverParams)> {
+    let params = self.grid.params();
+    iproduct!(self.solvers.iter().copied(), params).collect()
+  }
+
+  /// Counts the jobs the ensemble launches, without listing them.
+  pub fn count(&self) -> usize {
+    self.solvers.len().saturating_mul(self.grid.count())
+  }
+
+  /// Checks that the ensemble can be launched.
+  ///
+  /// # Returns
+  /// An error describing what's wrong with the ensemble, if anything.
+  pub fn validate(&self) -> Result<(), String> {
+    if self.name.trim().is_empty() {
+      return Err(String::from("name must not be empty"));
+    }
+    if self.solvers.is_empty() {
+      return Err(String::from("pick at least one solver"));
+    }
+    if self.grid.time_limits.contains(&0) {
+      return Err(String::from("time limits must be at least 1 second"));
+    }
+    if self.grid.threads.contains(&0) {
+      return Err(String::from("threads must be at least 1"));
+    }
+    let count = self.count();
+    if count > Self::MAX_JOBS {
+      return Err(format!(
+        "{count} jobs is more than the {} allowed",
+        Self::MAX_JOBS
+      ));
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_grid_parse_list() {
+    assert_eq!(Grid::parse_list::<u64>("60, 300,"), Ok(vec![60, 300]));
+    assert_eq!(Grid::parse_list::<u32>(""), Ok(vec![]));
+    assert!(Grid::parse_list::<u32>("1, two").is_err());
+    let gaps = Grid::parse_list::<Gap>("1%, 0.05").unwrap();
+    assert_eq!(gaps.len(), 2);
+  }
+
+  #[test]
+  fn test_ensemble_runs() {
+    let ensemble = Ensemble {
+      name: String::from("ensemble-1"),
+      solvers: vec![Solver::Highs, Solver::Cbc],
+      grid: Grid {
+        time_limits: vec![60, 300],
+        presolve: vec![true, false],
+        ..Grid::default()
+      },
+      location: Location::Local,
+    };
+    let runs = ensemble.runs();
+    assert_eq!(runs.len(), 8);
+    assert_eq!(ensemble.count(), 8);
+    assert_eq!(runs[0].0, Solver::Highs);
+    assert_eq!(runs[0].1.time_limit, Some(60));
+    assert_eq!(runs[0].1.mip_gap, None);
+    assert!(!runs[1].1.presolve);
+    assert_eq!(runs[4].0, Solver::Cbc);
+    assert_eq!(ensemble.validate(), Ok(()));
+  }
+
+  #[test]
+  fn test_ensemble_validate() {
+    let mut ensemble = Ensemble {
+      name: String::from("ensemble-1"),
+      ..Ensemble::default()
+    };
+    assert!(ensemble.validate().is_err());
+    ensemble.solvers = vec![Solver::Highs];
+    assert_eq!(ensemble.runs().len(), 1);
+    assert_eq!(ensemble.validate(), Ok(()));
+    ensemble.grid.threads = (1..=65).collect();
+    assert!(ensemble.validate().is_err());
+    ensemble.grid.threads = (1..=1000).collect();
+    ensemble.grid.time_limits = (1..=1000).collect();
+    ensemble.grid.mip_gaps = vec![Gap::from_str("1%").unwrap(); 1000];
+    assert_eq!(
+      ensemble.validate(),
+      Err(String::from("1000000000 jobs is more than the 64 allowed"))
+    );
+    ensemble.grid.threads = vec![0];
+    assert!(ensemble.validate().is_err());
+  }
+}
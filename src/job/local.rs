@@ -0,0 +1,334 @@
+use super::{parser, JobId, JobSpec, JobState};
+use crate::router::{Address, Cacheable, Kind, Message, Payload};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  process::Stdio,
+  sync::Arc,
+  time::Instant,
+};
+use tokio::{
+  io::{AsyncBufRead, AsyncBufReadExt, BufReader},
+  process::Command,
+  sync::{mpsc, Semaphore},
+};
+use tokio_util::sync::CancellationToken;
+
+/// Runs solver jobs as local processes.
+///
+/// Jobs are submitted and cancelled through the router. Each job waits in a
+/// queue until a slot is free, so that concurrent solvers don't compete for
+/// the same cores. State changes, and the events parsed from the solver's
+/// output, are sent back to the address that submitted the job; the output
+/// itself goes to the job's log stream.
+#[derive(Debug)]
+pub struct LocalRunner {
+  /// Cloned sender for routing messages to the Router.
+  message_tx_to_router: mpsc::UnboundedSender<Message>,
+  /// Unique receiver for messages from the Router.
+  message_rx_from_router: Option<mpsc::UnboundedReceiver<Message>>,
+  /// Cloneable sender for sending messages to itself.
+  pub message_tx_to_self: mpsc::UnboundedSender<Message>,
+}
+
+impl LocalRunner {
+  /// The number of jobs allowed to run at the same time.
+  const MAX_RUNNING: usize = 1;
+
+  /// Creates a new `LocalRunner`.
+  ///
+  /// # Arguments
+  ///
+  /// * `tx` - UnboundedSender for sending messages to the Router.
+  pub fn new(tx: mpsc::UnboundedSender<Message>) -> Self {
+    let (message_tx_to_self, message_rx_from_router) =
+      mpsc::unbounded_channel::<Message>();
+    Self {
+      message_tx_to_router: tx,
+      message_rx_from_router: Some(message_rx_from_router),
+      message_tx_to_self,
+    }
+  }
+
+  /// Starts accepting job submissions and cancellations from the router.
+  pub fn run(&mut self) {
+    let Some(mut message_rx_from_router) = self.message_rx_from_router.take()
+    else {
+      return;
+    };
+    let tx = self.message_tx_to_router.clone();
+    let slots = Arc::new(Semaphore::new(Self::MAX_RUNNING));
+
+    tokio::spawn(async move {
+      let mut jobs: HashMap<JobId, CancellationToken> = HashMap::new();
+      while let Some(message) = message_rx_from_router.recv().await {
+        jobs.retain(|_, token| !token.is_cancelled());
+        match message.payload {
+          Payload::SubmitJob(spec) => {
+            let token = CancellationToken::new();
+            jobs.insert(spec.id, token.clone());
+            let job = Job {
+              spec,
+              reply_to: message.source,
+              tx: tx.clone(),
+            };
+            tokio::spawn(job.execute(slots.clone(), token));
+          }
+          Payload::CancelJob(id) => {
+            if let Some(token) = jobs.remove(&id) {
+              token.cancel();
+            }
+          }
+          _ => {}
+        }
+      }
+    });
+  }
+}
+
+/// A submitted job and where to report on it.
+struct Job {
+  spec: JobSpec,
+  reply_to: Address,
+  tx: mpsc::UnboundedSender<Message>,
+}
+
+impl Job {
+  /// Waits for a free slot, then runs the solver to completion.
+  ///
+  /// The token is cancelled when the job stops, which also lets the runner
+  /// forget about it.
+  async fn execute(self, slots: Arc<Semaphore>, token: CancellationToken) {
+    let state = tokio::select! {
+      permit = slots.acquire_owned() => match permit {
+        Ok(_permit) => self.solve(&token).await,
+        Err(_) => JobState::Failed,
+      },
+      () = token.cancelled() => JobState::Cancelled,
+    };
+    self.state(state);
+    token.cancel();
+  }
+
+  /// Finds the solver and runs it with the job's parameters.
+  async fn solve(&self, token: &CancellationToken) -> JobState {
+    let Some(executable) = self.spec.solver.find() else {
+      self.log(format!("{} not found on PATH", self.spec.solver));
+      return JobState::Failed;
+    };
+    let options = match self.write_options() {
+      Ok(options) => options,
+      Err(e) => {
+        self.log(format!("failed to write solver options: {e}"));
+        return JobState::Failed;
+      }
+    };
+    let state = self.run(&executable, options.as_deref(), token).await;
+    if let Some(options) = options {
+      std::fs::remove_file(options).ok();
+    }
+    state
+  }
+
+  /// Writes the options the solver takes from a file, if there are any.
+  ///
+  /// # Returns
+  /// The path of the file, which the caller removes once the solver exits.
+  fn write_options(&self) -> std::io::Result<Option<PathBuf>> {
+    let Some(options) = self.spec.solver.options(&self.spec.params) else {
+      return Ok(None);
+    };
+    let path = std::env::temp_dir().join(format!(
+      "napali-{}-{}.opt",
+      std::process::id(),
+      self.spec.id.0
+    ));
+    std::fs::write(&path, options)?;
+    Ok(Some(path))
+  }
+
+  /// Runs the solver process and streams its output.
+  async fn run(
+    &self,
+    executable: &Path,
+    options: Option<&Path>,
+    token: &CancellationToken,
+  ) -> JobState {
+    let spec = &self.spec;
+    let mut child = match Command::new(executable)
+      .args(spec.solver.args(&spec.problem, &spec.params, options))
+      .stdin(Stdio::null())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .kill_on_drop(true)
+      .spawn()
+    {
+      Ok(child) => child,
+      Err(e) => {
+        self.log(format!("failed to start {}: {e}", self.spec.solver));
+        return JobState::Failed;
+      }
+    };
+    self.state(JobState::Running);
+    let started = Instant::now();
+    let mut parser = parser::for_solver(spec.solver);
+
+    let mut stdout = child.stdout.take().map(BufReader::new);
+    let mut stderr = child.stderr.take().map(BufReader::new);
+    let (mut stdout_buf, mut stderr_buf) = (Vec::new(), Vec::new());
+    while stdout.is_some() || stderr.is_some() {
+      tokio::select! {
+        line = async { read_line(stdout.as_mut()?, &mut stdout_buf).await },
+          if stdout.is_some() => match line {
+          Some(line) => self.output(line, parser.as_mut(), started),
+          None => stdout = None,
+        },
+        line = async { read_line(stderr.as_mut()?, &mut stderr_buf).await },
+          if stderr.is_some() => match line {
+          Some(line) => self.output(line, parser.as_mut(), started),
+          None => stderr = None,
+        },
+        () = token.cancelled() => {
+          child.kill().await.ok();
+          return JobState::Cancelled;
+        }
+      }
+    }
+
+    match child.wait().await {
+      Ok(status) if status.success() => JobState::Finished,
+      Ok(status) => {
+        self.log(format!("{} exited with {status}", self.spec.solver));
+        JobState::Failed
+      }
+      Err(e) => {
+        self.log(format!("failed to wait for {}: {e}", self.spec.solver));
+        JobState::Failed
+      }
+    }
+  }
+
+  /// Reports a state change.
+  fn state(&self, state: JobState) {
+    self.send(
+      self.reply_to.clone(),
+      Payload::JobState(self.spec.id, state),
+    );
+  }
+
+  /// Reports a line of solver output, and the events it reports if any.
+  fn output(
+    &self,
+    line: String,
+    parser: &mut dyn parser::LogParser,
+    started: Instant,
+  ) {
+    let events = parser.parse(&line);
+    if !events.is_empty() {
+      self.send(
+        self.reply_to.clone(),
+        Payload::JobEvents(self.spec.id, started.elapsed(), events),
+      );
+    }
+    self.log(line);
+  }
+
+  /// Reports a line of solver output on the job's log stream.
+  fn log(&self, line: String) {
+    let destination = Address::Job(self.spec.id);
+    self.send(destination, Payload::JobLog(self.spec.id, line));
+  }
+
+  fn send(&self, destination: Address, payload: Payload) {
+    self
+      .tx
+      .send(Message {
+        source: Address::LocalRunner,
+        destination,
+        payload,
+        tag: None,
+        cacheable: Cacheable::No,
+        kind: Kind::Tell,
+      })
+      .ok();
+  }
+}
+
+/// Reads the next line of solver output, without its line ending.
+///
+/// Bytes that aren't UTF-8 are replaced rather than ending the output, so
+/// that the pipe keeps being drained. A line cut short by cancelling the
+/// read is kept in `buf` and finished by the next call.
+///
+/// # Returns
+/// The line, or `None` once the output has ended.
+async fn read_line(
+  reader: &mut (impl AsyncBufRead + Unpin),
+  buf: &mut Vec<u8>,
+) -> Option<String> {
+  let read = reader.read_until(b'\n', buf).await.ok()?;
+  if read == 0 && buf.is_empty() {
+    return None;
+  }
+  if buf.ends_with(b"\n") {
+    buf.pop();
+    if buf.ends_with(b"\r") {
+      buf.pop();
+    }
+  }
+  let line = String::from_utf8_lossy(buf).into_owned();
+  buf.clear();
+  Some(line)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::job::Solver;
+  use color_eyre::eyre::Result;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_local_runner_new() {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let _ = LocalRunner::new(tx);
+  }
+
+  #[tokio::test]
+  async fn test_read_line() {
+    let mut output: &[u8] = b"Welcome\nProblem caf\xe9.mps\r\nDone";
+    let mut buf = Vec::new();
+    let mut lines = Vec::new();
+    while let Some(line) = read_line(&mut output, &mut buf).await {
+      lines.push(line);
+    }
+    assert_eq!(lines, ["Welcome", "Problem caf\u{fffd}.mps", "Done"]);
+  }
+
+  #[tokio::test]
+  async fn test_cancel_queued_job() -> Result<()> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+    let job = Job {
+      spec: JobSpec {
+        id: JobId(1),
+        problem: "afiro.mps".into(),
+        solver: Solver::Highs,
+        ..JobSpec::default()
+      },
+      reply_to: Address::Session,
+      tx,
+    };
+    // With no free slots, the job stays queued until it is cancelled.
+    let slots = Arc::new(Semaphore::new(0));
+    let token = CancellationToken::new();
+    token.cancel();
+    job.execute(slots, token).await;
+    let message = rx.recv().await.expect("state is reported");
+    assert_eq!(message.destination, Address::Session);
+    assert_eq!(
+      message.payload,
+      Payload::JobState(JobId(1), JobState::Cancelled)
+    );
+    Ok(())
+  }
+}
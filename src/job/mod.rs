@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+use std::{
+  fmt,
+  hash::{Hash, Hasher},
+  path::PathBuf,
+  time::Duration,
+};
+mod ensemble;
+mod local;
+mod params;
+pub mod parser;
+mod progress;
+mod solver;
+pub use ensemble::{Ensemble, Grid};
+pub use local::LocalRunner;
+pub use params::{Gap, ParamSet, ParamSets, SolverParams};
+pub use parser::SolverEvent;
+pub use progress::Progress;
+pub use solver::Solver;
+
+/// Identifies a job for the lifetime of the application.
+#[derive(
+  Default,
+  Debug,
+  Copy,
+  Clone,
+  PartialEq,
+  Eq,
+  PartialOrd,
+  Ord,
+  Hash,
+  Serialize,
+  Deserialize,
+)]
+pub struct JobId(pub u64);
+
+impl fmt::Display for JobId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "#{}", self.0)
+  }
+}
+
+/// The lifecycle state of a job.
+#[derive(
+  Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum JobState {
+  #[default]
+  Queued,
+  Running,
+  Finished,
+  Failed,
+  Cancelled,
+}
+
+impl JobState {
+  /// Returns whether the job has stopped and will not change state again.
+  pub fn is_done(self) -> bool {
+    matches!(
+      self,
+      JobState::Finished | JobState::Failed | JobState::Cancelled
+    )
+  }
+}
+
+impl fmt::Display for JobState {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let s = match self {
+      JobState::Queued => "queued",
+      JobState::Running => "running",
+      JobState::Finished => "finished",
+      JobState::Failed => "failed",
+      JobState::Cancelled => "cancelled",
+    };
+    write!(f, "{s}")
+  }
+}
+
+/// Where a job is executed.
+#[derive(
+  Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Location {
+  #[default]
+  Local,
+  Remote,
+}
+
+/// Describes a job to be run: which solver to apply to which problem, and
+/// with which parameters.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct JobSpec {
+  pub id: JobId,
+  pub problem: PathBuf,
+  pub solver: Solver,
+  pub params: SolverParams,
+  /// Name of the parameter set the job was submitted with, if any.
+  pub param_set: Option<String>,
+}
+
+/// An objective value.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct Objective(pub f64);
+
+// Values are compared bit for bit, so that equality is reflexive even for
+// NaN.
+impl PartialEq for Objective {
+  fn eq(&self, other: &Self) -> bool {
+    self.0.to_bits() == other.0.to_bits()
+  }
+}
+
+impl Eq for Objective {}
+
+impl Hash for Objective {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.to_bits().hash(state);
+  }
+}
+
+impl fmt::Display for Objective {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+/// What a solver reported about the solution it found.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct Solution {
+  /// The termination status, e.g. `optimal` or `infeasible`.
+  pub status: String,
+  /// The objective value of the best solution found, if any.
+  pub objective: Option<Objective>,
+}
+
+/// How a solve ended, read from the status a solver reported.
+///
+/// Solvers word their statuses differently, e.g. HiGHS says `Optimal` where
+/// CBC says `Optimal solution found`. Statuses that don't match one of the
+/// common outcomes are kept as reported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SolveStatus {
+  Optimal,
+  Infeasible,
+  Unbounded,
+  TimeLimit,
+  Other(String),
+}
+
+impl From<&str> for SolveStatus {
+  fn from(status: &str) -> Self {
+    let lower = status.to_lowercase();
+    let has = |word: &str| lower.contains(word);
+    if has("time limit") {
+      SolveStatus::TimeLimit
+    } else if has("infeasible") && has("unbounded") {
+      // Gurobi doesn't always tell which one
+      SolveStatus::Other(status.to_string())
+    } else if has("infeasible") {
+      SolveStatus::Infeasible
+    } else if has("unbounded") {
+      SolveStatus::Unbounded
+    } else if has("optimal") {
+      SolveStatus::Optimal
+    } else {
+      SolveStatus::Other(status.to_string())
+    }
+  }
+}
+
+impl fmt::Display for SolveStatus {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SolveStatus::Optimal => write!(f, "Optimal"),
+      SolveStatus::Infeasible => write!(f, "Infeasible"),
+      SolveStatus::Unbounded => write!(f, "Unbounded"),
+      SolveStatus::TimeLimit => write!(f, "Time limit"),
+      SolveStatus::Other(status) => write!(f, "{status}"),
+    }
+  }
+}
+
+/// A job as tracked by the user interface.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Job {
+  pub spec: JobSpec,
+  pub name: String,
+  pub location: Location,
+  pub state: JobState,
+  /// Name of the ensemble the job was launched with, if any.
+  pub ensemble: Option<String>,
+  /// What the solver found, once the job has finished.
+  pub solution: Option<Solution>,
+  /// How long the job ran for, once it has stopped.
+  pub elapsed: Option<Duration>,
+}
+
+impl Job {
+  /// Creates a new queued job.
+  pub fn new(spec: JobSpec, name: String, location: Location) -> Job {
+    Job {
+      spec,
+      name,
+      location,
+      state: JobState::Queued,
+      ensemble: None,
+      solution: None,
+      elapsed: None,
+    }
+  }
+
+  /// Returns the job's identifier.
+  pub fn id(&self) -> JobId {
+    self.spec.id
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_job_state_is_done() {
+    assert!(!JobState::Queued.is_done());
+    assert!(!JobState::Running.is_done());
+    assert!(JobState::Finished.is_done());
+    assert!(JobState::Failed.is_done());
+    assert!(JobState::Cancelled.is_done());
+  }
+
+  #[test]
+  fn test_job_new() {
+    let spec = JobSpec {
+      id: JobId(7),
+      ..JobSpec::default()
+    };
+    let job = Job::new(spec, String::from("afiro"), Location::Local);
+    assert_eq!(job.id(), JobId(7));
+    assert_eq!(job.state, JobState::Queued);
+    assert_eq!(job.id().to_string(), "#7");
+  }
+
+  #[test]
+  fn test_solve_status_from() {
+    assert_eq!(SolveStatus::from("Optimal"), SolveStatus::Optimal);
+    assert_eq!(
+      SolveStatus::from("Optimal solution found"),
+      SolveStatus::Optimal
+    );
+    assert_eq!(
+      SolveStatus::from("Model is infeasible"),
+      SolveStatus::Infeasible
+    );
+    assert_eq!(SolveStatus::from("unbounded"), SolveStatus::Unbounded);
+    assert_eq!(
+      SolveStatus::from("Stopped on time limit"),
+      SolveStatus::TimeLimit
+    );
+    let status = SolveStatus::from("Model is infeasible or unbounded");
+    assert_eq!(status.to_string(), "Model is infeasible or unbounded");
+    assert_eq!(SolveStatus::TimeLimit.to_string(), "Time limit");
+  }
+}
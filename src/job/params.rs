@@ -0,0 +1,274 @@
+use super::Solver;
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+  fmt, fs,
+  hash::{Hash, Hasher},
+  path::{Path, PathBuf},
+  str::FromStr,
+};
+
+/// A relative MIP gap, between 0 and 1.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Gap(f64);
+
+// Gaps are never NaN, so equality is reflexive.
+impl Eq for Gap {}
+
+impl Hash for Gap {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.to_bits().hash(state);
+  }
+}
+
+impl TryFrom<f64> for Gap {
+  type Error = String;
+
+  fn try_from(value: f64) -> Result<Self, Self::Error> {
+    if (0.0..=1.0).contains(&value) {
+      Ok(Gap(value))
+    } else {
+      Err(format!("MIP gap {value} is not between 0 and 1"))
+    }
+  }
+}
+
+impl From<Gap> for f64 {
+  fn from(gap: Gap) -> f64 {
+    gap.0
+  }
+}
+
+impl FromStr for Gap {
+  type Err = String;
+
+  /// Parses a gap given as a fraction, e.g. `0.01`, or a percentage, e.g.
+  /// `1%`.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (number, scale) = match s.trim().strip_suffix('%') {
+      Some(percent) => (percent, 100.0),
+      None => (s.trim(), 1.0),
+    };
+    let value = number
+      .trim()
+      .parse::<f64>()
+      .map_err(|_| format!("MIP gap {s:?} is not a number"))?;
+    Gap::try_from(value / scale)
+  }
+}
+
+impl fmt::Display for Gap {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+/// Tuning parameters passed to the solver.
+///
+/// Parameters left unset keep the solver's own defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SolverParams {
+  /// Seconds the solver may run before giving up.
+  #[serde(default)]
+  pub time_limit: Option<u64>,
+  /// Relative gap at which a MIP counts as solved.
+  #[serde(default)]
+  pub mip_gap: Option<Gap>,
+  /// Threads the solver may use.
+  #[serde(default)]
+  pub threads: Option<u32>,
+  /// Whether the problem is presolved.
+  #[serde(default = "presolve_default")]
+  pub presolve: bool,
+}
+
+fn presolve_default() -> bool {
+  true
+}
+
+impl Default for SolverParams {
+  fn default() -> Self {
+    SolverParams {
+      time_limit: None,
+      mip_gap: None,
+      threads: None,
+      presolve: presolve_default(),
+    }
+  }
+}
+
+impl SolverParams {
+  /// Checks that the parameters make sense.
+  ///
+  /// # Returns
+  /// An error describing the first parameter that doesn't.
+  pub fn validate(&self) -> Result<(), String> {
+    if self.time_limit == Some(0) {
+      return Err(String::from("time limit must be at least 1 second"));
+    }
+    if self.threads == Some(0) {
+      return Err(String::from("threads must be at least 1"));
+    }
+    Ok(())
+  }
+}
+
+impl fmt::Display for SolverParams {
+  /// Lists the parameters that differ from the solver's defaults.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut parts = Vec::new();
+    if let Some(secs) = self.time_limit {
+      parts.push(format!("{secs}s"));
+    }
+    if let Some(gap) = self.mip_gap {
+      parts.push(format!("gap {gap}"));
+    }
+    if let Some(threads) = self.threads {
+      parts.push(format!("{threads} threads"));
+    }
+    if !self.presolve {
+      parts.push(String::from("no presolve"));
+    }
+    if parts.is_empty() {
+      write!(f, "defaults")
+    } else {
+      write!(f, "{}", parts.join(", "))
+    }
+  }
+}
+
+/// A solver and the parameters to run it with, saved under a name.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct ParamSet {
+  pub name: String,
+  pub solver: Solver,
+  #[serde(default)]
+  pub params: SolverParams,
+}
+
+impl ParamSet {
+  /// Checks the set's name and parameters.
+  ///
+  /// # Returns
+  /// An error describing what's wrong with the set, if anything.
+  pub fn validate(&self) -> Result<(), String> {
+    if self.name.trim().is_empty() {
+      return Err(String::from("name must not be empty"));
+    }
+    self.params.validate()
+  }
+}
+
+/// The parameter sets saved in a workspace.
+///
+/// Sets are kept in `.napali/params.json` under the workspace root, so that
+/// they travel with the problems they were tuned for.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ParamSets {
+  /// File the sets are kept in.
+  path: PathBuf,
+  /// The sets, in the order they were first saved.
+  sets: Vec<ParamSet>,
+}
+
+impl ParamSets {
+  /// Loads the sets saved in a workspace.
+  ///
+  /// A missing file means no sets have been saved yet.
+  ///
+  /// # Arguments
+  /// - `root`: The workspace root.
+  pub fn load(root: &Path) -> Result<ParamSets> {
+    let path = root.join(".napali").join("params.json");
+    let sets = match fs::read_to_string(&path) {
+      Ok(contents) => serde_json::from_str(&contents)
+        .map_err(|e| eyre!("invalid parameter sets in {path:?}: {e}"))?,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+      Err(e) => return Err(eyre!("failed to read {path:?}: {e}")),
+    };
+    Ok(ParamSets { path, sets })
+  }
+
+  /// Saves a set, replacing any set of the same name, and writes the sets
+  /// back to the workspace.
+  pub fn save(&mut self, set: ParamSet) -> Result<()> {
+    match self.sets.iter_mut().find(|s| s.name == set.name) {
+      Some(existing) => *existing = set,
+      None => self.sets.push(set),
+    }
+    if let Some(parent) = self.path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    fs::write(&self.path, serde_json::to_string_pretty(&self.sets)?)
+      .map_err(|e| eyre!("failed to write {:?}: {e}", self.path))
+  }
+
+  /// Returns the set with the given name.
+  pub fn get(&self, name: &str) -> Option<&ParamSet> {
+    self.sets.iter().find(|set| set.name == name)
+  }
+
+  /// Returns the saved sets.
+  pub fn all(&self) -> &[ParamSet] {
+    &self.sets
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_gap_from_str() {
+    assert_eq!("0.01".parse::<Gap>(), Ok(Gap(0.01)));
+    assert_eq!("5%".parse::<Gap>(), Ok(Gap(0.05)));
+    assert!("1.5".parse::<Gap>().is_err());
+    assert!("-0.1".parse::<Gap>().is_err());
+    assert!("tiny".parse::<Gap>().is_err());
+    assert!(serde_json::from_str::<Gap>("2.0").is_err());
+  }
+
+  #[test]
+  fn test_param_set_validate() {
+    let mut set = ParamSet {
+      name: String::from("quick"),
+      ..ParamSet::default()
+    };
+    assert_eq!(set.validate(), Ok(()));
+    set.params.threads = Some(0);
+    assert!(set.validate().is_err());
+    set.params.threads = Some(4);
+    set.name = String::from(" ");
+    assert!(set.validate().is_err());
+  }
+
+  #[test]
+  fn test_param_sets_persist() -> Result<()> {
+    let root = std::env::temp_dir()
+      .join(format!("napali-params-{}", std::process::id()));
+    let mut sets = ParamSets::load(&root)?;
+    assert!(sets.all().is_empty());
+    let mut set = ParamSet {
+      name: String::from("quick"),
+      solver: Solver::Cbc,
+      params: SolverParams {
+        time_limit: Some(60),
+        mip_gap: Some(Gap(0.01)),
+        ..SolverParams::default()
+      },
+    };
+    sets.save(set.clone())?;
+    set.params.threads = Some(2);
+    sets.save(set.clone())?;
+    let loaded = ParamSets::load(&root)?;
+    assert_eq!(loaded.all(), &[set]);
+    fs::write(root.join(".napali").join("params.json"), "{")?;
+    assert!(ParamSets::load(&root).is_err());
+    fs::remove_dir_all(&root)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,133 @@
+use super::{count, objective, LogParser, SolverEvent};
+
+/// Reads CBC's output.
+#[derive(Debug, Default)]
+pub struct Cbc;
+
+impl Cbc {
+  /// Reads a `Cbc0010I After 100 nodes, 5 on tree, -464 best solution,
+  /// best possible -464.75 (0.52 seconds)` line.
+  fn status_line(line: &str) -> Option<Vec<SolverEvent>> {
+    let mut parts = line.split(", ");
+    let explored = count(parts.next()?, "nodes")?;
+    let open = count(parts.next()?, "on")?;
+    let mut events = vec![SolverEvent::Nodes { explored, open }];
+    let incumbent = parts.next()?.split_whitespace().next()?;
+    events.extend(objective(incumbent).map(SolverEvent::Incumbent));
+    let bound = parts.next()?.strip_prefix("best possible ")?;
+    let bound = bound.split_whitespace().next()?;
+    events.extend(objective(bound).map(SolverEvent::Bound));
+    Some(events)
+  }
+
+  /// Reads a `Cgl0004I processed model has 14 rows, 22 columns (...) and 60
+  /// elements` line.
+  fn presolved(line: &str) -> Option<SolverEvent> {
+    Some(SolverEvent::Presolved {
+      rows: count(line, "rows")?,
+      columns: count(line, "columns")?,
+      nonzeros: count(line, "elements")?,
+    })
+  }
+}
+
+impl LogParser for Cbc {
+  fn parse(&mut self, line: &str) -> Vec<SolverEvent> {
+    let line = line.trim();
+    let Some((code, rest)) = line.split_once(' ') else {
+      return Vec::new();
+    };
+    let events = match code {
+      "Cbc0010I" => Self::status_line(rest).unwrap_or_default(),
+      // New solutions, e.g. `Integer solution of -464 found by ...`
+      "Cbc0004I" | "Cbc0012I" => rest
+        .split_once("solution of ")
+        .and_then(|(_, rest)| objective(rest.split_whitespace().next()?))
+        .map(SolverEvent::Incumbent)
+        .into_iter()
+        .collect(),
+      // `At root node, 4 cuts changed objective from -470 to -466 ...`
+      "Cbc0013I" => count(rest, "cuts")
+        .map(SolverEvent::Cuts)
+        .into_iter()
+        .collect(),
+      // `Search completed - best objective -464, took 101 iterations and
+      // 120 nodes (0.60 seconds)`
+      "Cbc0001I" => count(rest, "nodes")
+        .map(|explored| SolverEvent::Nodes { explored, open: 0 })
+        .into_iter()
+        .collect(),
+      "Cgl0004I" => Self::presolved(rest).into_iter().collect(),
+      _ => Vec::new(),
+    };
+    if !events.is_empty() {
+      return events;
+    }
+    if let Some(status) = line.strip_prefix("Result - ") {
+      return vec![SolverEvent::Status(status.to_string())];
+    }
+    if let Some(value) = line.strip_prefix("Objective value:") {
+      return objective(value.trim())
+        .map(SolverEvent::Incumbent)
+        .into_iter()
+        .collect();
+    }
+    Vec::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::job::Objective;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_cbc_log() {
+    let mut parser = Cbc;
+    let events = include_str!("fixtures/cbc.log")
+      .lines()
+      .flat_map(|line| parser.parse(line))
+      .collect::<Vec<_>>();
+    let incumbent = |value| SolverEvent::Incumbent(Objective(value));
+    let nodes = |explored, open| SolverEvent::Nodes { explored, open };
+    assert_eq!(
+      events,
+      vec![
+        SolverEvent::Presolved {
+          rows: 14,
+          columns: 22,
+          nonzeros: 60
+        },
+        incumbent(-450.0),
+        SolverEvent::Cuts(4),
+        nodes(0, 1),
+        incumbent(-450.0),
+        SolverEvent::Bound(Objective(-466.0)),
+        incumbent(-464.0),
+        nodes(100, 5),
+        incumbent(-464.0),
+        SolverEvent::Bound(Objective(-464.75)),
+        nodes(120, 0),
+        SolverEvent::Status(String::from("Optimal solution found")),
+        incumbent(-464.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_cbc_no_solution_yet() {
+    let line = "Cbc0010I After 0 nodes, 1 on tree, 1e+50 best solution, best \
+                possible -470 (0.01 seconds)";
+    assert_eq!(
+      Cbc.parse(line),
+      vec![
+        SolverEvent::Nodes {
+          explored: 0,
+          open: 1
+        },
+        SolverEvent::Bound(Objective(-470.0))
+      ]
+    );
+  }
+}
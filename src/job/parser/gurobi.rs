@@ -0,0 +1,190 @@
+use super::{count, objective, LogParser, SolverEvent};
+
+/// How Gurobi describes the ways a solve can end.
+const STATUSES: [&str; 8] = [
+  "Optimal solution found",
+  "Model is infeasible",
+  "Model is unbounded",
+  "Model is infeasible or unbounded",
+  "Time limit reached",
+  "Solution limit reached",
+  "Node limit reached",
+  "Solve interrupted",
+];
+
+/// Reads Gurobi's output.
+#[derive(Debug, Default)]
+pub struct Gurobi {
+  /// Cuts counted so far in the `Cutting planes:` summary, while it's
+  /// being read.
+  cuts: Option<u64>,
+}
+
+impl Gurobi {
+  /// Reads a row of the branch-and-bound table, e.g.
+  /// `H    0     0       -462.0000000 -470.00000  1.73%     -    0s`.
+  fn table_row(line: &str) -> Option<Vec<SolverEvent>> {
+    let mut tokens = line.split_whitespace().collect::<Vec<_>>();
+    // Rows for new solutions start with `H` or `*`
+    if tokens.first().is_some_and(|t| *t == "H" || *t == "*") {
+      tokens.remove(0);
+    }
+    let n = tokens.len();
+    if n < 7
+      || !(tokens[n - 3].ends_with('%') || tokens[n - 3] == "-")
+      || tokens[n - 1].strip_suffix('s')?.parse::<u64>().is_err()
+    {
+      return None;
+    }
+    let explored = tokens[0].parse().ok()?;
+    let open = tokens[1].parse().ok()?;
+    let mut events = vec![SolverEvent::Nodes { explored, open }];
+    events.extend(objective(tokens[n - 5]).map(SolverEvent::Incumbent));
+    events.extend(objective(tokens[n - 4]).map(SolverEvent::Bound));
+    Some(events)
+  }
+
+  /// Reads the line after the status, e.g. `Best objective -4.64e+02, best
+  /// bound -4.64e+02, gap 0.0000%`.
+  fn best(line: &str) -> Vec<SolverEvent> {
+    let mut events = Vec::new();
+    for part in line.split(", ") {
+      if let Some(value) = part.strip_prefix("Best objective ") {
+        events.extend(objective(value).map(SolverEvent::Incumbent));
+      } else if let Some(value) = part.strip_prefix("best bound ") {
+        events.extend(objective(value).map(SolverEvent::Bound));
+      }
+    }
+    events
+  }
+}
+
+impl LogParser for Gurobi {
+  fn parse(&mut self, line: &str) -> Vec<SolverEvent> {
+    let line = line.trim();
+    if let Some(total) = self.cuts.as_mut() {
+      // The summary lists one kind of cut per line, e.g. `  Gomory: 3`
+      let cuts = line
+        .rsplit_once(": ")
+        .and_then(|(_, cuts)| cuts.parse::<u64>().ok());
+      match cuts {
+        Some(cuts) => {
+          *total += cuts;
+          return vec![SolverEvent::Cuts(*total)];
+        }
+        None => self.cuts = None,
+      }
+    }
+    if line == "Cutting planes:" {
+      self.cuts = Some(0);
+      return Vec::new();
+    }
+    if let Some(events) = Self::table_row(line) {
+      return events;
+    }
+    if let Some(size) = line.strip_prefix("Presolved: ") {
+      let presolved = || {
+        Some(SolverEvent::Presolved {
+          rows: count(size, "rows")?,
+          columns: count(size, "columns")?,
+          nonzeros: count(size, "nonzeros")?,
+        })
+      };
+      return presolved().into_iter().collect();
+    }
+    if let Some(value) =
+      line.strip_prefix("Found heuristic solution: objective ")
+    {
+      return objective(value)
+        .map(SolverEvent::Incumbent)
+        .into_iter()
+        .collect();
+    }
+    if line.starts_with("Explored ") {
+      return count(line, "nodes")
+        .map(|explored| SolverEvent::Nodes { explored, open: 0 })
+        .into_iter()
+        .collect();
+    }
+    if line.starts_with("Best objective ") {
+      return Self::best(line);
+    }
+    STATUSES
+      .iter()
+      .find(|status| line.starts_with(*status))
+      .map(|status| SolverEvent::Status(status.to_string()))
+      .into_iter()
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::job::Objective;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_gurobi_log() {
+    let mut parser = Gurobi::default();
+    let events = include_str!("fixtures/gurobi.log")
+      .lines()
+      .flat_map(|line| parser.parse(line))
+      .collect::<Vec<_>>();
+    let incumbent = |value| SolverEvent::Incumbent(Objective(value));
+    let bound = |value| SolverEvent::Bound(Objective(value));
+    let nodes = |explored, open| SolverEvent::Nodes { explored, open };
+    assert_eq!(
+      events,
+      vec![
+        incumbent(-450.0),
+        SolverEvent::Presolved {
+          rows: 14,
+          columns: 22,
+          nonzeros: 60
+        },
+        nodes(0, 0),
+        incumbent(-450.0),
+        bound(-470.0),
+        nodes(0, 0),
+        incumbent(-462.0),
+        bound(-470.0),
+        nodes(0, 0),
+        incumbent(-462.0),
+        bound(-466.0),
+        nodes(12, 3),
+        incumbent(-464.0),
+        bound(-465.0),
+        nodes(20, 0),
+        incumbent(-464.0),
+        bound(-464.0),
+        SolverEvent::Cuts(3),
+        SolverEvent::Cuts(8),
+        SolverEvent::Cuts(10),
+        nodes(20, 0),
+        SolverEvent::Status(String::from("Optimal solution found")),
+        incumbent(-464.0),
+        bound(-464.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_gurobi_no_incumbent() {
+    let mut parser = Gurobi::default();
+    let row =
+      "     0     0 -470.00000    0    2          - -470.00000      -  \
+               -    0s";
+    assert_eq!(
+      parser.parse(row),
+      vec![
+        SolverEvent::Nodes {
+          explored: 0,
+          open: 0
+        },
+        SolverEvent::Bound(Objective(-470.0))
+      ]
+    );
+    assert_eq!(parser.parse("Model is infeasible").len(), 1);
+  }
+}
@@ -0,0 +1,208 @@
+use super::{count, objective, LogParser, SolverEvent};
+use itertools::Itertools;
+
+/// Reads HiGHS's output.
+#[derive(Debug, Default)]
+pub struct Highs {
+  /// Whether the lines are part of the report printed after a MIP solve.
+  in_report: bool,
+}
+
+impl Highs {
+  /// Reads a row of the branch-and-bound table, e.g.
+  /// ` H   0   0   0   0.00%   3028   124034   97.56%  ...  0.0s`.
+  fn table_row(line: &str) -> Option<Vec<SolverEvent>> {
+    let mut tokens = line.split_whitespace().collect::<Vec<_>>();
+    // Rows for new solutions start with a letter naming the source
+    if tokens.first().is_some_and(|t| {
+      t.len() == 1 && t.starts_with(|c: char| c.is_ascii_alphabetic())
+    }) {
+      tokens.remove(0);
+    }
+    if tokens.len() < 12
+      || !tokens[3].ends_with('%')
+      || !tokens.last()?.ends_with('s')
+    {
+      return None;
+    }
+    let explored = tokens[0].parse().ok()?;
+    let open = tokens[1].parse().ok()?;
+    let mut events = vec![SolverEvent::Nodes { explored, open }];
+    events.extend(objective(tokens[4]).map(SolverEvent::Bound));
+    events.extend(objective(tokens[5]).map(SolverEvent::Incumbent));
+    events.extend(tokens[7].parse().ok().map(SolverEvent::Cuts));
+    Some(events)
+  }
+
+  /// Reads a line of the report printed after a MIP solve, e.g.
+  /// `  Primal bound      -464`.
+  fn report(line: &str) -> Vec<SolverEvent> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let event = match words.as_slice() {
+      ["Status", status @ ..] => Some(SolverEvent::Status(status.join(" "))),
+      ["Primal", "bound", value] => {
+        objective(value).map(SolverEvent::Incumbent)
+      }
+      ["Dual", "bound", value] => objective(value).map(SolverEvent::Bound),
+      ["Nodes", nodes] => nodes
+        .parse()
+        .ok()
+        .map(|explored| SolverEvent::Nodes { explored, open: 0 }),
+      _ => None,
+    };
+    event.into_iter().collect()
+  }
+
+  /// Reads the size of the presolved problem, from a line like
+  /// `14 rows, 22 cols, 60 nonzeros` or `Presolve : Reductions: rows
+  /// 22(-5); columns 27(-5); elements 71(-12)`.
+  fn presolved(line: &str) -> Option<SolverEvent> {
+    if let Some(reductions) = line.strip_prefix("Presolve : Reductions:") {
+      let size = |unit: &str| {
+        let (_, rest) = reductions.split_once(unit)?;
+        rest.trim_start().split('(').next()?.parse().ok()
+      };
+      return Some(SolverEvent::Presolved {
+        rows: size("rows")?,
+        columns: size("columns")?,
+        nonzeros: size("elements")?,
+      });
+    }
+    if line.split_whitespace().count() != 6 {
+      return None;
+    }
+    Some(SolverEvent::Presolved {
+      rows: count(line, "rows")?,
+      columns: count(line, "cols")?,
+      nonzeros: count(line, "nonzeros")?,
+    })
+  }
+}
+
+impl LogParser for Highs {
+  fn parse(&mut self, line: &str) -> Vec<SolverEvent> {
+    let trimmed = line.trim();
+    if trimmed == "Solving report" {
+      self.in_report = true;
+    }
+    if self.in_report {
+      return Self::report(trimmed);
+    }
+    if let Some(events) = Self::table_row(line) {
+      return events;
+    }
+    // LP solves end with `Model   status      : Optimal` and the like
+    if let Some((key, value)) = trimmed.split_once(':') {
+      let value = value.trim();
+      match key.split_whitespace().join(" ").as_str() {
+        "Model status" => {
+          return vec![SolverEvent::Status(value.to_string())];
+        }
+        "Objective value" => {
+          return objective(value)
+            .map(SolverEvent::Incumbent)
+            .into_iter()
+            .collect();
+        }
+        _ => {}
+      }
+    }
+    Self::presolved(trimmed).into_iter().collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::job::Objective;
+  use pretty_assertions::assert_eq;
+
+  fn parse(log: &str) -> Vec<SolverEvent> {
+    let mut parser = Highs::default();
+    log.lines().flat_map(|line| parser.parse(line)).collect()
+  }
+
+  #[test]
+  fn test_highs_mip_log() {
+    let events = parse(include_str!("fixtures/highs.log"));
+    let incumbents = events
+      .iter()
+      .filter_map(|e| match e {
+        SolverEvent::Incumbent(o) => Some(o.0),
+        _ => None,
+      })
+      .dedup()
+      .collect::<Vec<_>>();
+    assert_eq!(incumbents, vec![-450.0, -464.0]);
+    let bounds = events
+      .iter()
+      .filter_map(|e| match e {
+        SolverEvent::Bound(o) => Some(o.0),
+        _ => None,
+      })
+      .dedup()
+      .collect::<Vec<_>>();
+    assert_eq!(bounds, vec![-480.5, -470.0, -466.0, -465.0, -464.0]);
+    let presolved = SolverEvent::Presolved {
+      rows: 14,
+      columns: 22,
+      nonzeros: 60,
+    };
+    assert!(events.contains(&presolved));
+    assert!(events.contains(&SolverEvent::Cuts(18)));
+    assert!(events.contains(&SolverEvent::Nodes {
+      explored: 12,
+      open: 3
+    }));
+    assert_eq!(
+      &events[events.len() - 4..],
+      &[
+        SolverEvent::Status(String::from("Optimal")),
+        SolverEvent::Incumbent(Objective(-464.0)),
+        SolverEvent::Bound(Objective(-464.0)),
+        SolverEvent::Nodes {
+          explored: 20,
+          open: 0
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_highs_lp_log() {
+    let log = "Presolve : Reductions: rows 22(-5); columns 27(-5); elements \
+               71(-12)\nModel   status      : Optimal\nObjective value     : \
+               -4.6475314286e+02\n";
+    assert_eq!(
+      parse(log),
+      vec![
+        SolverEvent::Presolved {
+          rows: 22,
+          columns: 27,
+          nonzeros: 71
+        },
+        SolverEvent::Status(String::from("Optimal")),
+        SolverEvent::Incumbent(Objective(-464.75314286)),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_highs_ignores_other_lines() {
+    let mut parser = Highs::default();
+    assert!(parser.parse("Presolving model").is_empty());
+    assert!(parser.parse("   14 rows").is_empty());
+    let row = "         0       0         0   0.00%   -inf            inf  \
+               inf        0      0      0         0     0.0s";
+    assert_eq!(
+      parser.parse(row),
+      vec![
+        SolverEvent::Nodes {
+          explored: 0,
+          open: 0
+        },
+        SolverEvent::Cuts(0)
+      ]
+    );
+  }
+}
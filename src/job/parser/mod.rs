@@ -0,0 +1,135 @@
+use super::{Objective, Solver};
+use serde::{Deserialize, Serialize};
+
+mod cbc;
+mod gurobi;
+mod highs;
+
+/// Objective values at least this large stand for infinity in solver output.
+const INFINITY: f64 = 1e50;
+
+/// Something a solver reported about its progress.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SolverEvent {
+  /// Presolve finished, leaving a problem of this size.
+  Presolved {
+    rows: u64,
+    columns: u64,
+    nonzeros: u64,
+  },
+  /// A solution with this objective value was found.
+  Incumbent(Objective),
+  /// The best bound on the optimal objective value moved.
+  Bound(Objective),
+  /// Branch and bound has explored `explored` nodes, with `open` left.
+  Nodes { explored: u64, open: u64 },
+  /// The number of cuts added so far.
+  Cuts(u64),
+  /// The solver stopped, e.g. with `Optimal`.
+  Status(String),
+}
+
+/// Reads events from a solver's output, one line at a time.
+///
+/// Each solver gets its own parser, since no two solvers format their
+/// output alike. Parsers may keep state between lines, for reports that
+/// span several of them.
+pub trait LogParser: Send {
+  /// Reads the events reported on a line of output.
+  fn parse(&mut self, line: &str) -> Vec<SolverEvent>;
+}
+
+/// Returns a parser for the given solver's output.
+pub fn for_solver(solver: Solver) -> Box<dyn LogParser> {
+  match solver {
+    Solver::Highs => Box::<highs::Highs>::default(),
+    Solver::Cbc => Box::<cbc::Cbc>::default(),
+  }
+}
+
+/// Returns a parser for the solver whose banner is on the given line.
+///
+/// Logs are matched on their banner when it isn't certain which solver
+/// wrote them, as with the logs the IRX API returns for remote jobs.
+pub fn detect(line: &str) -> Option<Box<dyn LogParser>> {
+  let line = line.trim_start();
+  if line.starts_with("Running HiGHS") {
+    Some(Box::<highs::Highs>::default())
+  } else if line.starts_with("Welcome to the CBC MILP Solver") {
+    Some(Box::<cbc::Cbc>::default())
+  } else if line.starts_with("Gurobi Optimizer version") {
+    Some(Box::<gurobi::Gurobi>::default())
+  } else {
+    None
+  }
+}
+
+/// Reads the events from a whole log.
+///
+/// # Arguments
+/// - `solver`: The solver the log is expected from, used until a banner
+///   names another.
+/// - `log`: The log.
+pub fn parse_log(solver: Solver, log: &str) -> Vec<SolverEvent> {
+  let mut parser = for_solver(solver);
+  let mut events = Vec::new();
+  for line in log.lines() {
+    if let Some(detected) = detect(line) {
+      parser = detected;
+    }
+    events.extend(parser.parse(line));
+  }
+  events
+}
+
+/// Parses an objective value, treating infinite values as missing.
+fn objective(token: &str) -> Option<Objective> {
+  let value = token.trim_end_matches(',').parse::<f64>().ok()?;
+  (value.is_finite() && value.abs() < INFINITY).then_some(Objective(value))
+}
+
+/// Parses the number in front of the first word starting with `unit`, e.g.
+/// the 14 in `14 rows, 22 columns`.
+fn count(text: &str, unit: &str) -> Option<u64> {
+  let words = text.split_whitespace().collect::<Vec<_>>();
+  words
+    .windows(2)
+    .find(|pair| pair[1].starts_with(unit))
+    .and_then(|pair| pair[0].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_objective() {
+    assert_eq!(objective("-464.75"), Some(Objective(-464.75)));
+    assert_eq!(objective("1e+50"), None);
+    assert_eq!(objective("-inf"), None);
+    assert_eq!(objective("-"), None);
+  }
+
+  #[test]
+  fn test_count() {
+    let text = "processed model has 14 rows, 22 columns and 60 elements";
+    assert_eq!(count(text, "rows"), Some(14));
+    assert_eq!(count(text, "elements"), Some(60));
+    assert_eq!(count(text, "nodes"), None);
+  }
+
+  #[test]
+  fn test_parse_log_detects_solver() {
+    let log = "Gurobi Optimizer version 10.0.1\nPresolved: 14 rows, 22 \
+               columns, 60 nonzeros";
+    assert_eq!(
+      parse_log(Solver::Highs, log),
+      vec![SolverEvent::Presolved {
+        rows: 14,
+        columns: 22,
+        nonzeros: 60
+      }]
+    );
+  }
+}
@@ -0,0 +1,77 @@
+use super::{Objective, SolverEvent};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A snapshot of a solver's progress towards an optimal solution.
+#[derive(
+  Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct Progress {
+  /// Wall time since the job started.
+  pub elapsed: Duration,
+  /// Objective value of the best solution found so far.
+  pub primal: Option<Objective>,
+  /// Best bound on the optimal objective value.
+  pub dual: Option<Objective>,
+}
+
+impl Progress {
+  /// Returns the relative gap between the primal and dual bounds.
+  pub fn gap(&self) -> Option<f64> {
+    let (primal, dual) = (self.primal?.0, self.dual?.0);
+    Some((primal - dual).abs() / primal.abs().max(1e-10))
+  }
+
+  /// Returns the progress after an event.
+  ///
+  /// # Arguments
+  /// - `elapsed`: Wall time since the job started, when the event happened.
+  /// - `event`: The event.
+  ///
+  /// # Returns
+  /// The new progress, or `None` if the event doesn't report a bound.
+  pub fn after(&self, elapsed: Duration, event: &SolverEvent) -> Option<Self> {
+    let mut progress = Progress { elapsed, ..*self };
+    match event {
+      SolverEvent::Incumbent(objective) => progress.primal = Some(*objective),
+      SolverEvent::Bound(objective) => progress.dual = Some(*objective),
+      _ => return None,
+    }
+    Some(progress)
+  }
+
+  /// Returns the events that would bring a job to this progress.
+  pub fn events(&self) -> Vec<SolverEvent> {
+    let primal = self.primal.map(SolverEvent::Incumbent);
+    let dual = self.dual.map(SolverEvent::Bound);
+    primal.into_iter().chain(dual).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_progress_after() {
+    let second = Duration::from_secs(1);
+    let progress = Progress::default()
+      .after(second, &SolverEvent::Bound(Objective(90.0)))
+      .unwrap();
+    assert_eq!(progress.gap(), None);
+    let progress = progress
+      .after(2 * second, &SolverEvent::Incumbent(Objective(100.0)))
+      .unwrap();
+    assert_eq!(progress.elapsed, 2 * second);
+    assert_eq!(progress.gap(), Some(0.1));
+    assert_eq!(progress.after(second, &SolverEvent::Cuts(3)), None);
+    assert_eq!(
+      progress.events(),
+      vec![
+        SolverEvent::Incumbent(Objective(100.0)),
+        SolverEvent::Bound(Objective(90.0))
+      ]
+    );
+  }
+}
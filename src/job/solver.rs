@@ -0,0 +1,211 @@
+use super::SolverParams;
+use serde::{Deserialize, Serialize};
+use std::{
+  ffi::OsString,
+  fmt,
+  path::{Path, PathBuf},
+  str::FromStr,
+};
+use strum::{EnumIter, IntoEnumIterator};
+
+/// A solver that can be run as a local process.
+#[derive(
+  Default,
+  Debug,
+  Copy,
+  Clone,
+  PartialEq,
+  Eq,
+  Hash,
+  Serialize,
+  Deserialize,
+  EnumIter,
+)]
+pub enum Solver {
+  #[default]
+  Highs,
+  Cbc,
+}
+
+impl Solver {
+  /// Returns the name of the solver's executable.
+  pub fn executable(self) -> &'static str {
+    match self {
+      Solver::Highs => "highs",
+      Solver::Cbc => "cbc",
+    }
+  }
+
+  /// Returns the command-line arguments that solve the given problem file.
+  ///
+  /// # Arguments
+  /// - `problem`: The problem file.
+  /// - `params`: Parameters to tune the solver with.
+  /// - `options`: File holding the output of `options`, for solvers that
+  ///   take some parameters that way.
+  pub fn args(
+    self,
+    problem: &Path,
+    params: &SolverParams,
+    options: Option<&Path>,
+  ) -> Vec<OsString> {
+    let mut args: Vec<OsString> = Vec::new();
+    match self {
+      Solver::Highs => {
+        if let Some(secs) = params.time_limit {
+          args.extend(["--time_limit".into(), secs.to_string().into()]);
+        }
+        if !params.presolve {
+          args.extend(["--presolve".into(), "off".into()]);
+        }
+        if let Some(options) = options {
+          args.extend(["--options_file".into(), options.into()]);
+        }
+        args.push(problem.into());
+      }
+      Solver::Cbc => {
+        args.push(problem.into());
+        if let Some(secs) = params.time_limit {
+          args.extend(["-sec".into(), secs.to_string().into()]);
+        }
+        if let Some(gap) = params.mip_gap {
+          args.extend(["-ratioGap".into(), gap.to_string().into()]);
+        }
+        if let Some(threads) = params.threads {
+          args.extend(["-threads".into(), threads.to_string().into()]);
+        }
+        if !params.presolve {
+          args.extend(["-presolve".into(), "off".into()]);
+        }
+        args.extend(["-solve".into(), "-quit".into()]);
+      }
+    }
+    args
+  }
+
+  /// Returns the contents of an options file for parameters the solver
+  /// doesn't take on the command line.
+  ///
+  /// # Returns
+  /// The options, or `None` if there are none to pass this way.
+  pub fn options(self, params: &SolverParams) -> Option<String> {
+    match self {
+      Solver::Highs => {
+        let mut options = String::new();
+        if let Some(gap) = params.mip_gap {
+          options.push_str(&format!("mip_rel_gap = {gap}\n"));
+        }
+        if let Some(threads) = params.threads {
+          options.push_str(&format!("threads = {threads}\n"));
+        }
+        (!options.is_empty()).then_some(options)
+      }
+      Solver::Cbc => None,
+    }
+  }
+
+  /// Searches `PATH` for the solver's executable.
+  ///
+  /// # Returns
+  /// The full path to the executable, or `None` if it isn't installed.
+  pub fn find(self) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+      .map(|dir| dir.join(self.executable()))
+      .find(|path| path.is_file())
+  }
+
+  /// Returns the first solver, in order of preference, found on `PATH`.
+  pub fn detect() -> Option<Solver> {
+    Solver::iter().find(|solver| solver.find().is_some())
+  }
+}
+
+impl fmt::Display for Solver {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.executable())
+  }
+}
+
+impl FromStr for Solver {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Solver::iter()
+      .find(|solver| solver.executable().eq_ignore_ascii_case(s))
+      .ok_or_else(|| format!("unknown solver {s:?}"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_solver_args() {
+    let path = Path::new("afiro.mps");
+    let params = SolverParams::default();
+    assert_eq!(
+      Solver::Highs.args(path, &params, None),
+      vec![OsString::from("afiro.mps")]
+    );
+    assert_eq!(Solver::Cbc.args(path, &params, None).len(), 3);
+    assert_eq!(Solver::Highs.options(&params), None);
+  }
+
+  #[test]
+  fn test_solver_args_with_params() {
+    let path = Path::new("afiro.mps");
+    let params = SolverParams {
+      time_limit: Some(60),
+      mip_gap: "1%".parse().ok(),
+      threads: Some(4),
+      presolve: false,
+    };
+    let args = Solver::Cbc.args(path, &params, None);
+    let args = args.iter().map(|a| a.to_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(
+      args,
+      [
+        "afiro.mps",
+        "-sec",
+        "60",
+        "-ratioGap",
+        "0.01",
+        "-threads",
+        "4",
+        "-presolve",
+        "off",
+        "-solve",
+        "-quit"
+      ]
+    );
+    let options = Path::new("afiro.opt");
+    let args = Solver::Highs.args(path, &params, Some(options));
+    let args = args.iter().map(|a| a.to_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(
+      args,
+      [
+        "--time_limit",
+        "60",
+        "--presolve",
+        "off",
+        "--options_file",
+        "afiro.opt",
+        "afiro.mps"
+      ]
+    );
+    assert_eq!(
+      Solver::Highs.options(&params).as_deref(),
+      Some("mip_rel_gap = 0.01\nthreads = 4\n")
+    );
+  }
+
+  #[test]
+  fn test_solver_from_str() {
+    assert_eq!("HiGHS".parse::<Solver>(), Ok(Solver::Highs));
+    assert_eq!("cbc".parse::<Solver>(), Ok(Solver::Cbc));
+    assert!("gurobi".parse::<Solver>().is_err());
+  }
+}
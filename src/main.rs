@@ -7,6 +7,8 @@ mod cli;
 mod components;
 mod config;
 mod irx_client;
+mod job;
+mod problem;
 mod router;
 mod tui;
 mod utils;
@@ -27,7 +29,7 @@ async fn tokio_main() -> Result<()> {
     initialize_logging()?;
   }
   initialize_panic_handler()?;
-  let mut app = Box::pin(App::new(args.tick_rate, args.frame_rate)).await?;
+  let mut app = Box::pin(App::new(&args)).await?;
   app.run().await?;
   Ok(())
 }
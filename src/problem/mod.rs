@@ -0,0 +1,376 @@
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+  fmt, fs,
+  hash::{Hash, Hasher},
+  io::{BufRead, BufReader, Read},
+  path::{Path, PathBuf},
+};
+mod reader;
+mod scan;
+use reader::Reader;
+pub use scan::Entry;
+
+/// The most bytes of a file read to tell its `Kind`. Every problem file in
+/// a workspace is read on each scan, so this is kept small.
+const SNIFF_LIMIT: u64 = 64 << 10;
+
+/// The direction of optimization of a model's objective.
+#[derive(
+  Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Sense {
+  #[default]
+  Minimize,
+  Maximize,
+}
+
+impl fmt::Display for Sense {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Sense::Minimize => write!(f, "min"),
+      Sense::Maximize => write!(f, "max"),
+    }
+  }
+}
+
+/// The layout of an MPS document.
+#[derive(
+  Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Format {
+  #[default]
+  Free,
+  Fixed,
+}
+
+/// Distinguishes pure linear programs from mixed-integer programs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Kind {
+  Lp,
+  Mip,
+}
+
+impl Kind {
+  /// Determines whether an MPS file holds an LP or a MIP without parsing it.
+  ///
+  /// Scans for integer markers in `COLUMNS` and integer bound types in
+  /// `BOUNDS`, stopping at the first one found. Only the first
+  /// `SNIFF_LIMIT` bytes are read, so a large file without integer markers
+  /// near its start can't be told from an LP.
+  ///
+  /// # Arguments
+  /// - `path`: Path to an MPS file.
+  ///
+  /// # Returns
+  /// The problem's `Kind`, `None` if the limit was reached before it could
+  /// be told, or an error if the file can't be read.
+  pub fn sniff(path: &Path) -> Result<Option<Kind>> {
+    let file = fs::File::open(path)?.take(SNIFF_LIMIT);
+    for line in BufReader::new(file).lines() {
+      let line = line?;
+      let is_integer_bound = line.starts_with(' ')
+        && matches!(line.split_whitespace().next(), Some("BV" | "UI" | "LI"));
+      if line.contains("'INTORG'") || is_integer_bound {
+        return Ok(Some(Kind::Mip));
+      }
+      if line.starts_with("ENDATA") {
+        return Ok(Some(Kind::Lp));
+      }
+    }
+    Ok(None)
+  }
+}
+
+impl fmt::Display for Kind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Kind::Lp => write!(f, "LP"),
+      Kind::Mip => write!(f, "MIP"),
+    }
+  }
+}
+
+/// The comparison applied by a constraint row.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RowKind {
+  Equal,
+  LessEqual,
+  GreaterEqual,
+}
+
+/// A constraint row of a model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Row {
+  pub name: String,
+  pub kind: RowKind,
+  pub rhs: f64,
+  pub range: Option<f64>,
+}
+
+/// A column (decision variable) of a model.
+///
+/// `entries` holds the nonzero constraint coefficients as pairs of row index
+/// and value. An `upper` bound of `None` means the column is unbounded above.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Column {
+  pub name: String,
+  pub integer: bool,
+  pub cost: f64,
+  pub lower: f64,
+  pub upper: Option<f64>,
+  pub entries: Vec<(usize, f64)>,
+}
+
+/// An in-memory optimization model read from an MPS document.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Model {
+  pub name: String,
+  pub format: Format,
+  pub sense: Sense,
+  pub objective: Option<String>,
+  pub offset: f64,
+  pub rows: Vec<Row>,
+  pub columns: Vec<Column>,
+}
+
+impl Model {
+  /// Parses a model from the contents of an MPS document.
+  ///
+  /// The document is read line by line so that errors can be attributed to
+  /// a line number.
+  ///
+  /// # Arguments
+  /// - `contents`: The MPS document, in free or fixed format.
+  ///
+  /// # Returns
+  /// The parsed `Model`, or an error describing the first problem found.
+  pub fn parse(contents: &str) -> Result<Model> {
+    Ok(Reader::read(contents)?)
+  }
+
+  /// Returns the number of constraint rows, excluding the objective.
+  pub fn num_rows(&self) -> usize {
+    self.rows.len()
+  }
+
+  /// Returns the number of columns.
+  pub fn num_columns(&self) -> usize {
+    self.columns.len()
+  }
+
+  /// Returns the number of nonzero constraint coefficients.
+  pub fn num_nonzeros(&self) -> usize {
+    self.columns.iter().map(|c| c.entries.len()).sum()
+  }
+
+  /// Returns the number of integer columns.
+  pub fn num_integers(&self) -> usize {
+    self.columns.iter().filter(|c| c.integer).count()
+  }
+
+  /// Returns whether the model is an LP or a MIP.
+  pub fn kind(&self) -> Kind {
+    if self.num_integers() > 0 {
+      Kind::Mip
+    } else {
+      Kind::Lp
+    }
+  }
+}
+
+/// An optimization problem loaded from disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Problem {
+  /// The file the problem was loaded from.
+  pub path: PathBuf,
+  /// The parsed model.
+  pub model: Model,
+}
+
+// Problems are sent through the router once loaded, so they must be `Eq`
+// and `Hash` like every payload. A model with a NaN coefficient isn't equal
+// to itself, which at worst keeps two such payloads apart.
+impl Eq for Problem {}
+
+impl Hash for Problem {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.path.hash(state);
+    self.model.name.hash(state);
+  }
+}
+
+impl Problem {
+  /// Loads and parses an MPS file.
+  ///
+  /// # Arguments
+  /// - `path`: Path to a `.mps` file.
+  ///
+  /// # Returns
+  /// The loaded `Problem`, or an error if the file can't be read or parsed.
+  pub fn load(path: &Path) -> Result<Problem> {
+    let contents = fs::read_to_string(path)
+      .map_err(|e| eyre!("failed to read {}: {e}", path.display()))?;
+    let mut model = Model::parse(&contents)?;
+    if model.name.is_empty() {
+      model.name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    }
+    Ok(Problem {
+      path: path.to_path_buf(),
+      model,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  const FREE: &str = "\
+NAME          TESTLP
+ROWS
+ N  COST
+ L  LIM1
+ G  LIM2
+ E  MYEQN
+COLUMNS
+    X1        COST         1.0   LIM1         1.0
+    X1        LIM2         1.0
+    MARKER    'MARKER'     'INTORG'
+    X2        COST         2.0   LIM1         1.0
+    X2        MYEQN       -1.0
+    MARKER    'MARKER'     'INTEND'
+    X3        COST        -1.0   MYEQN        1.0
+RHS
+    RHS       LIM1         4.0   LIM2         1.0
+    RHS       MYEQN        7.0
+BOUNDS
+ UP BND       X1           4.0
+ MI BND       X2
+ENDATA
+";
+
+  const FIXED: &str = "\
+NAME          FIXED
+ROWS
+ N  OBJ
+ L  ROW ONE
+COLUMNS
+    COL ONE   OBJ       1.0            ROW ONE   2.0
+RHS
+    RHS       ROW ONE   3.0
+ENDATA
+";
+
+  #[test]
+  fn test_read_free() -> Result<()> {
+    let model = Reader::read(FREE)?;
+    assert_eq!(model.name, "TESTLP");
+    assert_eq!(model.format, Format::Free);
+    assert_eq!(model.num_rows(), 3);
+    assert_eq!(model.num_columns(), 3);
+    assert_eq!(model.num_nonzeros(), 5);
+    assert_eq!(model.num_integers(), 1);
+    assert_eq!(model.kind(), Kind::Mip);
+    assert_eq!(model.columns[0].upper, Some(4.0));
+    assert_eq!(model.columns[1].lower, f64::NEG_INFINITY);
+    assert_eq!(model.rows[2].rhs, 7.0);
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_fixed() -> Result<()> {
+    let model = Reader::read(FIXED)?;
+    assert_eq!(model.format, Format::Fixed);
+    assert_eq!(model.rows[0].name, "ROW ONE");
+    assert_eq!(model.columns[0].name, "COL ONE");
+    assert_eq!(model.columns[0].entries, vec![(0, 2.0)]);
+    assert_eq!(model.kind(), Kind::Lp);
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_objsense() -> Result<()> {
+    let contents = FREE.replace("ROWS\n", "OBJSENSE\n    MAX\nROWS\n");
+    let model = Reader::read(&contents)?;
+    assert_eq!(model.sense, Sense::Maximize);
+    Ok(())
+  }
+
+  #[test]
+  fn test_read_errors() {
+    let unknown_row = FREE.replace("X3        COST", "X3        NOPE");
+    let error = Reader::read(&unknown_row).unwrap_err();
+    assert_eq!(error.line, 14);
+    let bad_number = FREE.replace("4.0   LIM2", "four  LIM2");
+    let error = Reader::read(&bad_number).unwrap_err();
+    assert_eq!(error.line, 16);
+    assert_eq!(error.to_string(), "line 16: invalid number \"four\"");
+    let truncated = FREE.replace("ENDATA\n", "");
+    let error = Reader::read(&truncated).unwrap_err();
+    assert_eq!(error.message, "missing ENDATA");
+  }
+
+  #[test]
+  fn test_kind_sniff() -> Result<()> {
+    let dir = std::env::temp_dir().join("napali-test-kind-sniff");
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("mip.mps"), FREE)?;
+    fs::write(dir.join("lp.mps"), FIXED)?;
+    let columns = "    X1        COST         1.0\n".repeat(40_000);
+    fs::write(
+      dir.join("big.mps"),
+      format!("ROWS\nCOLUMNS\n{columns}ENDATA\n"),
+    )?;
+    assert_eq!(Kind::sniff(&dir.join("mip.mps"))?, Some(Kind::Mip));
+    assert_eq!(Kind::sniff(&dir.join("lp.mps"))?, Some(Kind::Lp));
+    assert_eq!(Kind::sniff(&dir.join("big.mps"))?, None);
+    fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_model_parse() -> Result<()> {
+    let objsense = FREE.replace("ROWS\n", "OBJSENSE\n    MAX\nROWS\n");
+    assert_eq!(Model::parse(FREE)?, Reader::read(FREE)?);
+    assert_eq!(Model::parse(FIXED)?.columns[0].name, "COL ONE");
+    assert_eq!(Model::parse(&objsense)?.sense, Sense::Maximize);
+    let bad_number = FREE.replace("4.0   LIM2", "four  LIM2");
+    let error = Model::parse(&bad_number).unwrap_err();
+    assert_eq!(error.to_string(), "line 16: invalid number \"four\"");
+    Ok(())
+  }
+
+  #[test]
+  fn test_problem_load() -> Result<()> {
+    let dir = std::env::temp_dir().join("napali-test-problem-load");
+    fs::create_dir_all(&dir)?;
+    let objsense = FREE.replace("ROWS\n", "OBJSENSE\n    MAX\nROWS\n");
+    for (file, contents) in [
+      ("free.mps", FREE),
+      ("fixed.mps", FIXED),
+      ("max.mps", &objsense),
+    ] {
+      fs::write(dir.join(file), contents)?;
+    }
+    let free = Problem::load(&dir.join("free.mps"))?;
+    assert_eq!(free.model.num_columns(), 3);
+    assert_eq!(free.path, dir.join("free.mps"));
+    let fixed = Problem::load(&dir.join("fixed.mps"))?;
+    assert_eq!(fixed.model.format, Format::Fixed);
+    assert_eq!(fixed.model.rows[0].name, "ROW ONE");
+    let max = Problem::load(&dir.join("max.mps"))?;
+    assert_eq!(max.model.sense, Sense::Maximize);
+    fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_problem_load_missing() {
+    assert!(Problem::load(Path::new("/nonexistent/problem.mps")).is_err());
+  }
+}
@@ -0,0 +1,456 @@
+use super::{Column, Format, Model, Row, RowKind, Sense};
+use std::{
+  collections::{HashMap, HashSet},
+  fmt,
+};
+
+/// An error encountered while reading an MPS document.
+///
+/// Carries the 1-based line number of the offending line so that it can be
+/// reported back to the user alongside the message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+  /// The 1-based line number where the error was found.
+  pub line: usize,
+  /// A human readable description of the error.
+  pub message: String,
+}
+
+impl ParseError {
+  fn new(line: usize, message: impl Into<String>) -> Self {
+    ParseError {
+      line,
+      message: message.into(),
+    }
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "line {}: {}", self.line, self.message)
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The sections of an MPS document, in the order they may appear.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Section {
+  Name,
+  ObjSense,
+  Rows,
+  Columns,
+  Rhs,
+  Ranges,
+  Bounds,
+  EndData,
+}
+
+impl Section {
+  /// Maps a section header keyword onto its `Section`.
+  fn from_keyword(keyword: &str) -> Option<Section> {
+    match keyword {
+      "NAME" => Some(Section::Name),
+      "OBJSENSE" => Some(Section::ObjSense),
+      "ROWS" => Some(Section::Rows),
+      "COLUMNS" => Some(Section::Columns),
+      "RHS" => Some(Section::Rhs),
+      "RANGES" => Some(Section::Ranges),
+      "BOUNDS" => Some(Section::Bounds),
+      "ENDATA" => Some(Section::EndData),
+      _ => None,
+    }
+  }
+}
+
+/// Character ranges of the six fields of a fixed format MPS data line.
+const FIXED_FIELDS: [(usize, usize); 6] =
+  [(1, 3), (4, 12), (14, 22), (24, 36), (39, 47), (49, 61)];
+
+/// Line oriented reader that builds a `Model` from an MPS document.
+///
+/// Both free (whitespace separated) and fixed (column positioned) formats
+/// are accepted. Each data line is first split on whitespace; if that does
+/// not yield a valid record for the current section, the line is re-read
+/// using the fixed format field positions, which permits spaces in names.
+#[derive(Debug, Default)]
+pub struct Reader {
+  model: Model,
+  section: Option<Section>,
+  row_index: HashMap<String, usize>,
+  column_index: HashMap<String, usize>,
+  ignored_rows: HashSet<String>,
+  in_integer_block: bool,
+  fixed_lines: usize,
+}
+
+impl Reader {
+  /// Reads a complete MPS document.
+  ///
+  /// # Arguments
+  /// - `contents`: The MPS document.
+  ///
+  /// # Returns
+  /// The parsed `Model`, or the first `ParseError` encountered.
+  pub fn read(contents: &str) -> Result<Model, ParseError> {
+    let mut reader = Reader::default();
+    let mut last = 0;
+    for (index, raw) in contents.lines().enumerate() {
+      last = index + 1;
+      reader.line(last, raw)?;
+      if reader.section == Some(Section::EndData) {
+        break;
+      }
+    }
+    match reader.section {
+      Some(Section::EndData) => {}
+      _ => return Err(ParseError::new(last, "missing ENDATA")),
+    }
+    if reader.model.columns.is_empty() {
+      return Err(ParseError::new(last, "no COLUMNS found"));
+    }
+    reader.model.format = if reader.fixed_lines > 0 {
+      Format::Fixed
+    } else {
+      Format::Free
+    };
+    Ok(reader.model)
+  }
+
+  /// Processes a single line of the document.
+  fn line(&mut self, number: usize, raw: &str) -> Result<(), ParseError> {
+    let line = raw.trim_end();
+    if line.trim().is_empty() || line.starts_with('*') {
+      return Ok(());
+    }
+    if !line.starts_with(char::is_whitespace) {
+      return self.header(number, line);
+    }
+    match self.section {
+      Some(Section::ObjSense) => self.objective_sense(number, line.trim()),
+      Some(Section::Rows) => self.row(number, line),
+      Some(Section::Columns) => self.column(number, line),
+      Some(Section::Rhs) => self.rhs(number, line),
+      Some(Section::Ranges) => self.range(number, line),
+      Some(Section::Bounds) => self.bound(number, line),
+      _ => Err(ParseError::new(number, "data line outside of a section")),
+    }
+  }
+
+  /// Processes a section header line.
+  fn header(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+    let mut fields = line.split_whitespace();
+    let keyword = fields.next().unwrap_or_default();
+    let section = Section::from_keyword(keyword).ok_or_else(|| {
+      ParseError::new(number, format!("unknown section {keyword:?}"))
+    })?;
+    if let Some(previous) = self.section {
+      if section <= previous {
+        return Err(ParseError::new(
+          number,
+          format!("section {keyword} is out of order"),
+        ));
+      }
+    }
+    match section {
+      Section::Name => {
+        self.model.name = line[keyword.len()..].trim().to_string();
+      }
+      Section::ObjSense => {
+        if let Some(sense) = fields.next() {
+          self.objective_sense(number, sense)?;
+        }
+      }
+      _ => {}
+    }
+    self.section = Some(section);
+    Ok(())
+  }
+
+  /// Processes an `OBJSENSE` value.
+  fn objective_sense(
+    &mut self,
+    number: usize,
+    value: &str,
+  ) -> Result<(), ParseError> {
+    self.model.sense = match value {
+      "MIN" | "MINIMIZE" => Sense::Minimize,
+      "MAX" | "MAXIMIZE" => Sense::Maximize,
+      _ => {
+        return Err(ParseError::new(
+          number,
+          format!("invalid objective sense {value:?}"),
+        ))
+      }
+    };
+    Ok(())
+  }
+
+  /// Processes a line of the `ROWS` section.
+  fn row(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+    let fields = self.fields(line, |f| f.len() == 2);
+    let [kind, name] = fields.as_slice() else {
+      return Err(ParseError::new(number, "expected a row type and name"));
+    };
+    let kind = match kind.as_str() {
+      "N" => None,
+      "E" => Some(RowKind::Equal),
+      "L" => Some(RowKind::LessEqual),
+      "G" => Some(RowKind::GreaterEqual),
+      _ => {
+        return Err(ParseError::new(
+          number,
+          format!("invalid row type {kind:?}"),
+        ))
+      }
+    };
+    if self.row_index.contains_key(name)
+      || self.ignored_rows.contains(name)
+      || self.model.objective.as_ref() == Some(name)
+    {
+      return Err(ParseError::new(number, format!("duplicate row {name:?}")));
+    }
+    match kind {
+      // Only the first free row is the objective; any others are ignored.
+      None if self.model.objective.is_none() => {
+        self.model.objective = Some(name.clone());
+      }
+      None => {
+        self.ignored_rows.insert(name.clone());
+      }
+      Some(kind) => {
+        self.row_index.insert(name.clone(), self.model.rows.len());
+        self.model.rows.push(Row {
+          name: name.clone(),
+          kind,
+          rhs: 0.0,
+          range: None,
+        });
+      }
+    }
+    Ok(())
+  }
+
+  /// Processes a line of the `COLUMNS` section.
+  fn column(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+    if line.contains("'MARKER'") {
+      if line.contains("'INTORG'") {
+        self.in_integer_block = true;
+      } else if line.contains("'INTEND'") {
+        self.in_integer_block = false;
+      } else {
+        return Err(ParseError::new(number, "unknown MARKER"));
+      }
+      return Ok(());
+    }
+    let fields = self.fields(line, |f| {
+      (f.len() == 3 || f.len() == 5) && Self::numbers_at(f, 2)
+    });
+    if fields.len() != 3 && fields.len() != 5 {
+      return Err(ParseError::new(
+        number,
+        "expected a column name and one or two row/value pairs",
+      ));
+    }
+    let name = &fields[0];
+    let index = match self.column_index.get(name) {
+      Some(index) => *index,
+      None => {
+        let index = self.model.columns.len();
+        self.column_index.insert(name.clone(), index);
+        self.model.columns.push(Column {
+          name: name.clone(),
+          integer: self.in_integer_block,
+          ..Column::default()
+        });
+        index
+      }
+    };
+    for pair in fields[1..].chunks(2) {
+      let value = Self::number(number, &pair[1])?;
+      if self.model.objective.as_ref() == Some(&pair[0]) {
+        self.model.columns[index].cost = value;
+      } else if !self.ignored_rows.contains(&pair[0]) {
+        let row = self.row_named(number, &pair[0])?;
+        self.model.columns[index].entries.push((row, value));
+      }
+    }
+    Ok(())
+  }
+
+  /// Processes a line of the `RHS` section.
+  fn rhs(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+    for (row, value) in self.pairs(number, line)? {
+      match row {
+        Some(row) => self.model.rows[row].rhs = value,
+        // A right-hand side on the objective row is a constant offset.
+        None => self.model.offset = -value,
+      }
+    }
+    Ok(())
+  }
+
+  /// Processes a line of the `RANGES` section.
+  fn range(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+    for (row, value) in self.pairs(number, line)? {
+      let row = row.ok_or_else(|| {
+        ParseError::new(number, "the objective row cannot have a range")
+      })?;
+      self.model.rows[row].range = Some(value);
+    }
+    Ok(())
+  }
+
+  /// Processes a line of the `BOUNDS` section.
+  fn bound(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+    let fields = self.fields(line, |f| match f.len() {
+      4 => Self::numbers_at(f, 3),
+      3 => matches!(f[0].as_str(), "FR" | "MI" | "PL" | "BV"),
+      _ => false,
+    });
+    let (kind, column, value) = match fields.as_slice() {
+      [kind, _, column, value] => (kind, column, Some(value)),
+      [kind, _, column] => (kind, column, None),
+      _ => {
+        return Err(ParseError::new(
+          number,
+          "expected a bound type, bound name, column and value",
+        ))
+      }
+    };
+    let index = *self.column_index.get(column).ok_or_else(|| {
+      ParseError::new(number, format!("unknown column {column:?}"))
+    })?;
+    let value = value.map(|v| Self::number(number, v)).transpose()?;
+    let bounds = &mut self.model.columns[index];
+    match (kind.as_str(), value) {
+      ("UP", Some(v)) => bounds.upper = Some(v),
+      ("LO", Some(v)) => bounds.lower = v,
+      ("FX", Some(v)) => {
+        bounds.lower = v;
+        bounds.upper = Some(v);
+      }
+      ("FR", _) => {
+        bounds.lower = f64::NEG_INFINITY;
+        bounds.upper = None;
+      }
+      ("MI", _) => bounds.lower = f64::NEG_INFINITY,
+      ("PL", _) => bounds.upper = None,
+      ("BV", _) => {
+        bounds.integer = true;
+        bounds.lower = 0.0;
+        bounds.upper = Some(1.0);
+      }
+      ("UI", Some(v)) => {
+        bounds.integer = true;
+        bounds.upper = Some(v);
+      }
+      ("LI", Some(v)) => {
+        bounds.integer = true;
+        bounds.lower = v;
+      }
+      _ => {
+        return Err(ParseError::new(
+          number,
+          format!("invalid bound type {kind:?}"),
+        ))
+      }
+    }
+    Ok(())
+  }
+
+  /// Reads the row/value pairs of an `RHS` or `RANGES` line.
+  ///
+  /// The leading vector name is optional in free format. Rows are returned
+  /// as indices, with `None` standing for the objective row.
+  fn pairs(
+    &mut self,
+    number: usize,
+    line: &str,
+  ) -> Result<Vec<(Option<usize>, f64)>, ParseError> {
+    let fields = self.fields(line, |f| match f.len() {
+      2 | 4 => Self::numbers_at(f, 1),
+      3 | 5 => Self::numbers_at(f, 2),
+      _ => false,
+    });
+    let pairs = match fields.len() {
+      2 | 4 => &fields[..],
+      3 | 5 => &fields[1..],
+      _ => {
+        return Err(ParseError::new(
+          number,
+          "expected one or two row/value pairs",
+        ))
+      }
+    };
+    pairs
+      .chunks(2)
+      .filter(|pair| !self.ignored_rows.contains(&pair[0]))
+      .map(|pair| {
+        let value = Self::number(number, &pair[1])?;
+        if self.model.objective.as_ref() == Some(&pair[0]) {
+          Ok((None, value))
+        } else {
+          Ok((Some(self.row_named(number, &pair[0])?), value))
+        }
+      })
+      .collect()
+  }
+
+  /// Looks up the index of a constraint row by name.
+  fn row_named(&self, number: usize, name: &str) -> Result<usize, ParseError> {
+    self
+      .row_index
+      .get(name)
+      .copied()
+      .ok_or_else(|| ParseError::new(number, format!("unknown row {name:?}")))
+  }
+
+  /// Checks that every other field, starting at `start`, is numeric.
+  fn numbers_at(fields: &[String], start: usize) -> bool {
+    fields[start..]
+      .iter()
+      .step_by(2)
+      .all(|f| f.parse::<f64>().is_ok())
+  }
+
+  /// Parses a numeric field.
+  fn number(number: usize, field: &str) -> Result<f64, ParseError> {
+    field
+      .parse::<f64>()
+      .map_err(|_| ParseError::new(number, format!("invalid number {field:?}")))
+  }
+
+  /// Splits a data line into fields.
+  ///
+  /// The line is split on whitespace first. If `valid` rejects the result,
+  /// the fixed format column positions are tried instead.
+  fn fields(
+    &mut self,
+    line: &str,
+    valid: impl Fn(&[String]) -> bool,
+  ) -> Vec<String> {
+    let free = line
+      .split_whitespace()
+      .map(String::from)
+      .collect::<Vec<_>>();
+    if valid(&free) {
+      return free;
+    }
+    let fixed = FIXED_FIELDS
+      .iter()
+      .filter_map(|(start, end)| line.get(*start..(*end).min(line.len())))
+      .map(str::trim)
+      .enumerate()
+      // The first field (row or bound type) is blank on column lines.
+      .filter(|(i, f)| !(f.is_empty() && (*i == 0 || *i > 2)))
+      .map(|(_, f)| f.to_string())
+      .collect::<Vec<_>>();
+    if valid(&fixed) {
+      self.fixed_lines += 1;
+      fixed
+    } else {
+      free
+    }
+  }
+}
@@ -0,0 +1,100 @@
+use super::Kind;
+use serde::{Deserialize, Serialize};
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+/// A directory or problem file found under a workspace root.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Entry {
+  pub path: PathBuf,
+  pub name: String,
+  /// Whether the file holds an LP or a MIP, or `None` if the start of the
+  /// file has no integer markers but it's too large to read to its end.
+  pub kind: Option<Kind>,
+  /// Size of the file in bytes.
+  pub size: u64,
+  /// The entries of a directory, or `None` for a file.
+  pub children: Option<Vec<Entry>>,
+}
+
+impl Entry {
+  /// Scans a directory for problem files and subdirectories containing them.
+  ///
+  /// Reads up to the first `SNIFF_LIMIT` bytes of every problem file found
+  /// to tell its `Kind`, so this blocks and is best run off the UI thread.
+  ///
+  /// # Arguments
+  /// - `dir`: The directory to scan.
+  /// - `depth`: How many levels of subdirectories to descend into.
+  pub fn scan(dir: &Path, depth: usize) -> Vec<Entry> {
+    let Ok(entries) = fs::read_dir(dir) else {
+      return Vec::new();
+    };
+    let mut entries = entries
+      .filter_map(|entry| entry.ok())
+      .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+      .filter_map(|entry| {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if path.is_dir() {
+          if depth == 0 {
+            return None;
+          }
+          let children = Self::scan(&path, depth - 1);
+          (!children.is_empty()).then_some(Entry {
+            path,
+            name,
+            kind: None,
+            size: 0,
+            children: Some(children),
+          })
+        } else if Self::is_problem(&path) {
+          Some(Entry {
+            kind: Kind::sniff(&path).ok().flatten(),
+            size: entry.metadata().map(|m| m.len()).unwrap_or_default(),
+            path,
+            name,
+            children: None,
+          })
+        } else {
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+    entries.sort_by(|a, b| {
+      (a.children.is_none(), &a.name).cmp(&(b.children.is_none(), &b.name))
+    });
+    entries
+  }
+
+  /// Returns whether a path looks like an MPS file.
+  fn is_problem(path: &Path) -> bool {
+    path
+      .extension()
+      .is_some_and(|ext| ext.eq_ignore_ascii_case("mps"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_scan() -> std::io::Result<()> {
+    let root = std::env::temp_dir().join("napali-test-workspaces-scan");
+    fs::create_dir_all(root.join("miplib"))?;
+    fs::create_dir_all(root.join("empty"))?;
+    fs::write(root.join("miplib").join("a.mps"), "NAME A\nENDATA\n")?;
+    fs::write(root.join("b.MPS"), "NAME B\nENDATA\n")?;
+    fs::write(root.join("notes.txt"), "")?;
+    let entries = Entry::scan(&root, 4);
+    let names = entries.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["miplib", "b.MPS"]);
+    assert_eq!(entries[1].kind, Some(Kind::Lp));
+    fs::remove_dir_all(root)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,185 @@
+use super::{Address, Cacheable, Kind, Message, Payload};
+use std::{collections::HashMap, mem::Discriminant};
+
+/// Identifies a question: who it's asked of, and what kind of payload it
+/// carries.
+type CacheKey = (Address, Discriminant<Payload>);
+
+/// The router's cache of answers to questions.
+///
+/// A reply marked `Cacheable::Yes` is kept per (destination, payload kind)
+/// of the question it answers, and later asks of the same kind are answered
+/// from it without bothering the service again. A service invalidates its
+/// cached answers by sending any untagged `Tell` marked `Cacheable::Yes`,
+/// which it does whenever the value behind them changes.
+#[derive(Debug, Default)]
+pub struct MessageCache {
+  /// Questions that are on their way to a service, by tag.
+  asked: HashMap<String, CacheKey>,
+  /// Cached answers.
+  replies: HashMap<CacheKey, Message>,
+}
+
+impl MessageCache {
+  /// Answers a question from the cache.
+  ///
+  /// # Parameters
+  ///
+  /// * `message`: The message about to be routed.
+  ///
+  /// # Returns
+  ///
+  /// The cached reply, addressed to the asker and carrying its tag, if the
+  /// message is a question with a cached answer.
+  pub fn lookup(&self, message: &Message) -> Option<Message> {
+    if message.kind != Kind::Ask {
+      return None;
+    }
+    self.replies.get(&Self::key(message)).map(|reply| Message {
+      destination: message.source.clone(),
+      tag: message.tag.clone(),
+      ..reply.clone()
+    })
+  }
+
+  /// Updates the cache from a message about to be routed.
+  ///
+  /// Remembers tagged questions, caches cacheable replies to them, and
+  /// drops a service's answers when it announces that they're stale.
+  ///
+  /// # Parameters
+  ///
+  /// * `message`: The message about to be routed.
+  pub fn observe(&mut self, message: &Message) {
+    match (&message.kind, &message.tag) {
+      (Kind::Ask, Some(tag)) => {
+        self.asked.insert(tag.clone(), Self::key(message));
+      }
+      (Kind::Tell, Some(tag)) => {
+        if let Some(key) = self.asked.remove(tag) {
+          if message.cacheable == Cacheable::Yes {
+            self.replies.insert(key, message.clone());
+          }
+        }
+      }
+      (Kind::Tell, None) if message.cacheable == Cacheable::Yes => {
+        self.invalidate(&message.source);
+      }
+      _ => {}
+    }
+  }
+
+  /// Forgets a question that won't be answered, such as one that couldn't
+  /// be delivered.
+  ///
+  /// # Parameters
+  ///
+  /// * `tag`: The tag of the question.
+  pub fn forget(&mut self, tag: &str) {
+    self.asked.remove(tag);
+  }
+
+  /// Forgets the questions whose askers stopped waiting, such as ones that
+  /// timed out, so that `asked` only holds questions still in flight.
+  ///
+  /// # Parameters
+  ///
+  /// * `waiting`: Whether someone still waits for the reply to a tag.
+  pub fn expire(&mut self, waiting: impl Fn(&str) -> bool) {
+    self.asked.retain(|tag, _| waiting(tag));
+  }
+
+  /// Drops all cached answers from a service.
+  ///
+  /// # Parameters
+  ///
+  /// * `address`: The service whose answers are stale.
+  pub fn invalidate(&mut self, address: &Address) {
+    self
+      .replies
+      .retain(|(destination, _), _| destination != address);
+  }
+
+  /// Returns the key of a question.
+  fn key(message: &Message) -> CacheKey {
+    (
+      message.destination.clone(),
+      std::mem::discriminant(&message.payload),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  fn ask(tag: &str) -> Message {
+    Message {
+      source: Address::StateDisplay,
+      destination: Address::IrxClient,
+      payload: Payload::Empty,
+      tag: Some(String::from(tag)),
+      cacheable: Cacheable::No,
+      kind: Kind::Ask,
+    }
+  }
+
+  fn reply(tag: Option<&str>, text: &str) -> Message {
+    Message {
+      source: Address::IrxClient,
+      destination: Address::StateDisplay,
+      payload: Payload::String(String::from(text)),
+      tag: tag.map(String::from),
+      cacheable: Cacheable::Yes,
+      kind: Kind::Tell,
+    }
+  }
+
+  #[test]
+  fn test_cache_and_invalidate() {
+    let mut cache = MessageCache::default();
+    assert_eq!(cache.lookup(&ask("a")), None);
+    cache.observe(&ask("a"));
+    cache.observe(&reply(Some("a"), "first"));
+
+    let cached = cache.lookup(&ask("b")).expect("answered from the cache");
+    assert_eq!(cached.tag, Some(String::from("b")));
+    assert_eq!(cached.payload, Payload::String(String::from("first")));
+
+    let question = Message {
+      payload: Payload::String(String::from("other")),
+      ..ask("c")
+    };
+    assert_eq!(cache.lookup(&question), None);
+
+    cache.observe(&reply(None, "changed"));
+    assert_eq!(cache.lookup(&ask("d")), None);
+  }
+
+  #[test]
+  fn test_uncacheable_reply() {
+    let mut cache = MessageCache::default();
+    cache.observe(&ask("a"));
+    cache.observe(&Message {
+      cacheable: Cacheable::No,
+      ..reply(Some("a"), "first")
+    });
+    assert_eq!(cache.lookup(&ask("b")), None);
+  }
+
+  #[test]
+  fn test_timed_out_ask() {
+    let mut cache = MessageCache::default();
+    cache.observe(&ask("a"));
+    cache.observe(&ask("b"));
+    cache.expire(|tag| tag == "b");
+    assert_eq!(cache.asked.len(), 1);
+    cache.forget("b");
+    assert!(cache.asked.is_empty());
+
+    // A late reply to a forgotten question isn't cached.
+    cache.observe(&reply(Some("a"), "late"));
+    assert_eq!(cache.lookup(&ask("c")), None);
+  }
+}
@@ -0,0 +1,780 @@
+use crate::irx_client::{
+  api::ApiKey, Account, Connectivity, KeySource, KeyStatus, Registration,
+};
+use crate::job::{JobId, JobSpec, JobState, Solution, SolverEvent};
+use crate::problem::{Entry, Problem};
+use color_eyre::eyre::{eyre, Result};
+use email_address::EmailAddress;
+use serde::{Deserialize, Serialize};
+use std::{
+  collections::{HashMap, VecDeque},
+  fmt,
+  hash::Hash,
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex, RwLock,
+  },
+  time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{self, instrument};
+mod cache;
+use cache::MessageCache;
+
+/// Represents the payload of a message in the application.
+///
+/// This enum encapsulates different types of data that can be sent as a message payload,
+/// such as credentials, email addresses, job requests and reports, or generic strings.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Payload {
+  #[default]
+  Empty,
+  KeyStatus(KeyStatus),
+  ApiKey(Option<ApiKey>, KeySource),
+  Account(Account),
+  RegenerateKey,
+  RevokeKey,
+  Connectivity(Connectivity),
+  Email(EmailAddress),
+  Registration(Registration),
+  String(String),
+  SubmitJob(JobSpec),
+  CancelJob(JobId),
+  JobState(JobId, JobState),
+  JobLog(JobId, String),
+  JobSolution(JobId, Solution),
+  JobEvents(JobId, Duration, Vec<SolverEvent>),
+  /// The problem files found under a workspace root.
+  Workspace(PathBuf, Vec<Entry>),
+  /// A problem file loaded, or why it couldn't be.
+  ProblemLoaded(PathBuf, Result<Box<Problem>, String>),
+  DeadLetter(Box<DeadLetter>),
+}
+
+/// Defines the possible addresses for message routing.
+///
+/// Addresses are used to identify different components or services in the application
+/// that can send or receive messages. Those carrying an ID belong to things
+/// that come and go while the application runs, and are registered and
+/// unregistered on the fly.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Address {
+  #[default]
+  Drop,
+  Router,
+  IrxClient,
+  LocalRunner,
+  Internals,
+  StateDisplay,
+  Session,
+  Home,
+  App,
+  /// The log stream of a single job.
+  Job(JobId),
+  /// A session tab, by name.
+  SessionTab(String),
+}
+
+/// Indicates whether a message is cacheable.
+///
+/// `Cacheable::Yes` on a reply lets the router answer later questions of the
+/// same kind with it, while `Cacheable::No` implies that the message should
+/// not be cached. An untagged `Tell` marked `Cacheable::Yes` tells the router
+/// that the sender's cached replies are stale.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Cacheable {
+  #[default]
+  No,
+  Yes,
+}
+
+/// Represents the type of a message.
+///
+/// `Kind::Tell` indicates a message that conveys information or a command,
+/// while `Kind::Ask` represents a request for information.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Kind {
+  #[default]
+  Tell,
+  Ask,
+}
+
+/// Struct for a message in the application's messaging system.
+///
+/// Contains details like the source and destination addresses, the payload,
+/// optional tags, cacheability, and the kind of message. A reply to an
+/// `Ask` carries the same tag as the request, which is how the router
+/// matches the two up.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct Message {
+  pub source: Address,
+  pub destination: Address,
+  pub payload: Payload,
+  pub tag: Option<String>,
+  pub cacheable: Cacheable,
+  pub kind: Kind,
+}
+
+/// Why the router couldn't deliver a message.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub enum Undeliverable {
+  /// Nothing is registered at the destination address.
+  #[default]
+  Unregistered,
+  /// The destination's receiver has been dropped.
+  Closed,
+}
+
+impl fmt::Display for Undeliverable {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Undeliverable::Unregistered => write!(f, "nothing is registered there"),
+      Undeliverable::Closed => write!(f, "its receiver is gone"),
+    }
+  }
+}
+
+/// A message the router couldn't deliver, and why.
+#[derive(
+  Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+pub struct DeadLetter {
+  pub message: Message,
+  pub reason: Undeliverable,
+}
+
+impl fmt::Display for DeadLetter {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "undeliverable message from {:?} to {:?}: {}",
+      self.message.source, self.message.destination, self.reason
+    )
+  }
+}
+
+/// The dead-letter queue: counts of undeliverable messages, and the most
+/// recent ones.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetters {
+  /// Number of messages sent to an unregistered address.
+  pub unregistered: u32,
+  /// Number of messages sent to an address whose receiver is gone.
+  pub closed: u32,
+  /// The most recent dead letters, oldest first.
+  pub recent: VecDeque<DeadLetter>,
+}
+
+impl DeadLetters {
+  /// How many dead letters are kept for inspection.
+  const CAPACITY: usize = 32;
+
+  /// Records an undeliverable message.
+  fn push(&mut self, letter: DeadLetter) {
+    match letter.reason {
+      Undeliverable::Unregistered => {
+        self.unregistered = self.unregistered.saturating_add(1);
+      }
+      Undeliverable::Closed => self.closed = self.closed.saturating_add(1),
+    }
+    if self.recent.len() == Self::CAPACITY {
+      self.recent.pop_front();
+    }
+    self.recent.push_back(letter);
+  }
+}
+
+/// Type alias for a table mapping addresses to message senders.
+type ChannelTable = HashMap<Address, mpsc::UnboundedSender<Message>>;
+
+/// Type alias for the channel table shared by the router and its handles.
+type SharedChannelTable = Arc<RwLock<ChannelTable>>;
+
+/// Type alias for a table mapping tags of unanswered asks to their waiters.
+type Pending = Arc<Mutex<HashMap<String, oneshot::Sender<Message>>>>;
+
+/// Type alias for the dead-letter queue shared by the router and its handles.
+type SharedDeadLetters = Arc<Mutex<DeadLetters>>;
+
+/// Represents a router in the messaging system.
+///
+/// The router is responsible for directing messages to the appropriate destination
+/// based on the address. Replies to asks made through a `RouterHandle` are
+/// handed to the waiting caller instead. Questions with a cached answer are
+/// answered by the router itself. Messages that can't be delivered
+/// end up in a dead-letter queue, and the `App` is told about each of them.
+#[derive(Debug)]
+pub struct Router {
+  channel_table: SharedChannelTable,
+  message_rx_from_self: Option<mpsc::UnboundedReceiver<Message>>,
+  message_tx_to_self: mpsc::UnboundedSender<Message>,
+  pending: Pending,
+  next_tag: Arc<AtomicU64>,
+  dead_letters: SharedDeadLetters,
+}
+
+impl Router {
+  /// Constructs a new `Router` with specified message channels.
+  ///
+  /// # Parameters
+  ///
+  /// * `tx`: The sender channel for the Router itself.
+  ///
+  /// # Returns
+  ///
+  /// `Result<(Self, mpsc::UnboundedSender<Message>)>` - A new Router instance and its sender.
+  #[instrument]
+  pub async fn new(
+    tx: mpsc::UnboundedSender<Message>,
+  ) -> Result<(Self, mpsc::UnboundedSender<Message>)> {
+    let (message_tx_to_self, message_rx_from_self) =
+      mpsc::unbounded_channel::<Message>();
+    let mut channel_table = ChannelTable::new();
+    channel_table.insert(Address::App, tx);
+    channel_table.insert(Address::Router, message_tx_to_self.clone());
+    Ok((
+      Self {
+        channel_table: Arc::new(RwLock::new(channel_table)),
+        message_rx_from_self: Some(message_rx_from_self),
+        message_tx_to_self: message_tx_to_self.clone(),
+        pending: Pending::default(),
+        next_tag: Arc::new(AtomicU64::new(0)),
+        dead_letters: SharedDeadLetters::default(),
+      },
+      message_tx_to_self,
+    ))
+  }
+
+  /// Creates a handle for sending messages and asking questions through the router.
+  pub fn handle(&self) -> RouterHandle {
+    RouterHandle {
+      tx: self.message_tx_to_self.clone(),
+      channel_table: self.channel_table.clone(),
+      pending: self.pending.clone(),
+      next_tag: self.next_tag.clone(),
+      dead_letters: self.dead_letters.clone(),
+    }
+  }
+
+  /// Registers a sender for a specific address in the router's channel table.
+  ///
+  /// This works before and after the router runs; use a `RouterHandle` to
+  /// register from elsewhere once it does.
+  ///
+  /// # Parameters
+  ///
+  /// * `addr`: The address to register.
+  /// * `tx`: The sender channel associated with the address.
+  pub fn register(
+    &mut self,
+    addr: Address,
+    tx: mpsc::UnboundedSender<Message>,
+  ) {
+    Self::insert(&self.channel_table, addr, tx);
+  }
+
+  /// Adds or replaces an entry in a channel table.
+  fn insert(
+    channel_table: &SharedChannelTable,
+    addr: Address,
+    tx: mpsc::UnboundedSender<Message>,
+  ) {
+    channel_table
+      .write()
+      .expect("channel table is not poisoned")
+      .insert(addr, tx);
+  }
+
+  /// Starts the routing process for incoming messages.
+  ///
+  /// Listens for messages and routes them to the appropriate destination based on the address.
+  pub fn run(&mut self) {
+    let mut message_rx_from_self = self
+      .message_rx_from_self
+      .take()
+      .expect("router has its own receiver"); // TODO replace all uses of expect()
+    let channel_table = self.channel_table.clone();
+    let pending = self.pending.clone();
+    let dead_letters = self.dead_letters.clone();
+    tokio::spawn(async move {
+      let mut cache = MessageCache::default();
+      loop {
+        if let Some(message) = message_rx_from_self.recv().await {
+          let message = match cache.lookup(&message) {
+            Some(reply) => reply,
+            None => {
+              if message.kind == Kind::Ask {
+                let pending =
+                  pending.lock().expect("pending asks are not poisoned");
+                cache.expire(|tag| pending.contains_key(tag));
+              }
+              cache.observe(&message);
+              message
+            }
+          };
+          if let Some(message) = Self::answer(message, &pending) {
+            let channel_table =
+              channel_table.read().expect("channel table is not poisoned");
+            if let Err(letter) = Self::route(message, &channel_table) {
+              if let Some(tag) = &letter.message.tag {
+                cache.forget(tag);
+              }
+              Self::bury(letter, &channel_table, &pending, &dead_letters);
+            }
+          }
+        }
+      }
+    });
+  }
+
+  /// Hands a reply to the caller waiting on its tag.
+  ///
+  /// # Parameters
+  ///
+  /// * `message`: The message to be routed.
+  /// * `pending`: The table of unanswered asks.
+  ///
+  /// # Returns
+  ///
+  /// The message if nobody is waiting for it, so it can be routed as usual.
+  fn answer(message: Message, pending: &Pending) -> Option<Message> {
+    if message.kind != Kind::Tell {
+      return Some(message);
+    }
+    let waiter = message.tag.as_ref().and_then(|tag| {
+      pending
+        .lock()
+        .expect("pending asks are not poisoned")
+        .remove(tag)
+    });
+    match waiter {
+      Some(waiter) => {
+        // The caller may have timed out and stopped waiting.
+        waiter.send(message).ok();
+        None
+      }
+      None => Some(message),
+    }
+  }
+
+  /// Routes a message to the appropriate destination.
+  ///
+  /// # Parameters
+  ///
+  /// * `message`: The message to be routed.
+  /// * `channel_table`: The table mapping addresses to message senders.
+  ///
+  /// # Returns
+  ///
+  /// The message as a dead letter if it couldn't be delivered.
+  fn route(
+    message: Message,
+    channel_table: &ChannelTable,
+  ) -> std::result::Result<(), Box<DeadLetter>> {
+    let (message, reason) = match channel_table.get(&message.destination) {
+      Some(tx) => match tx.send(message) {
+        Ok(()) => return Ok(()),
+        Err(e) => (e.0, Undeliverable::Closed),
+      },
+      None => (message, Undeliverable::Unregistered),
+    };
+    Err(Box::new(DeadLetter { message, reason }))
+  }
+
+  /// Puts an undeliverable message in the dead-letter queue.
+  ///
+  /// Whoever asked the question is released right away rather than left to
+  /// time out, and the `App` is told so it can surface the error.
+  ///
+  /// # Parameters
+  ///
+  /// * `letter`: The undeliverable message.
+  /// * `channel_table`: The table mapping addresses to message senders.
+  /// * `pending`: The table of unanswered asks.
+  /// * `dead_letters`: The dead-letter queue.
+  fn bury(
+    letter: Box<DeadLetter>,
+    channel_table: &ChannelTable,
+    pending: &Pending,
+    dead_letters: &SharedDeadLetters,
+  ) {
+    log::warn!("{letter}");
+    if let (Kind::Ask, Some(tag)) = (&letter.message.kind, &letter.message.tag)
+    {
+      pending
+        .lock()
+        .expect("pending asks are not poisoned")
+        .remove(tag);
+    }
+    dead_letters
+      .lock()
+      .expect("dead letters are not poisoned")
+      .push(DeadLetter::clone(&letter));
+    // Notices that can't reach the App are only logged, never re-queued.
+    if letter.message.destination != Address::App {
+      let notice = Message {
+        source: Address::Router,
+        destination: Address::App,
+        payload: Payload::DeadLetter(letter),
+        tag: None,
+        cacheable: Cacheable::No,
+        kind: Kind::Tell,
+      };
+      Self::route(notice, channel_table).ok();
+    }
+  }
+}
+
+/// A cloneable handle for talking to other components through the router.
+///
+/// Besides fire-and-forget sends, it supports asking a question and
+/// awaiting the answer. Each ask gets a unique tag; the responder replies
+/// with a `Tell` carrying the same tag, which the router hands back to the
+/// caller.
+#[derive(Debug, Clone)]
+pub struct RouterHandle {
+  tx: mpsc::UnboundedSender<Message>,
+  channel_table: SharedChannelTable,
+  pending: Pending,
+  next_tag: Arc<AtomicU64>,
+  dead_letters: SharedDeadLetters,
+}
+
+impl RouterHandle {
+  /// Registers a sender for an address while the router runs.
+  ///
+  /// # Parameters
+  ///
+  /// * `addr`: The address to register.
+  /// * `tx`: The sender channel associated with the address.
+  pub fn register(&self, addr: Address, tx: mpsc::UnboundedSender<Message>) {
+    Router::insert(&self.channel_table, addr, tx);
+  }
+
+  /// Removes an address from the router's channel table.
+  ///
+  /// Messages sent to it afterwards end up in the dead-letter queue.
+  ///
+  /// # Parameters
+  ///
+  /// * `addr`: The address to unregister.
+  ///
+  /// # Returns
+  ///
+  /// The sender that was registered for the address, if any.
+  pub fn unregister(
+    &self,
+    addr: &Address,
+  ) -> Option<mpsc::UnboundedSender<Message>> {
+    self
+      .channel_table
+      .write()
+      .expect("channel table is not poisoned")
+      .remove(addr)
+  }
+
+  /// Sends a message through the router without waiting for a reply.
+  pub fn send(&self, message: Message) -> Result<()> {
+    self
+      .tx
+      .send(message)
+      .map_err(|e| eyre!("router is gone: {e}"))
+  }
+
+  /// Asks another component a question and waits for its reply.
+  ///
+  /// # Parameters
+  ///
+  /// * `source`: The address asking the question.
+  /// * `destination`: The address expected to reply.
+  /// * `payload`: The question.
+  /// * `timeout`: How long to wait for the reply.
+  ///
+  /// # Returns
+  ///
+  /// The reply, or an error if it didn't arrive in time or the question
+  /// couldn't be delivered.
+  pub async fn ask(
+    &self,
+    source: Address,
+    destination: Address,
+    payload: Payload,
+    timeout: Duration,
+  ) -> Result<Message> {
+    let n = self.next_tag.fetch_add(1, Ordering::Relaxed);
+    let tag = format!("{source:?}-{n}");
+    let (reply_tx, reply_rx) = oneshot::channel();
+    self
+      .pending
+      .lock()
+      .expect("pending asks are not poisoned")
+      .insert(tag.clone(), reply_tx);
+    let request = Message {
+      source,
+      destination: destination.clone(),
+      payload,
+      tag: Some(tag.clone()),
+      cacheable: Cacheable::No,
+      kind: Kind::Ask,
+    };
+    if let Err(e) = self.send(request) {
+      self.forget(&tag);
+      return Err(e);
+    }
+    match tokio::time::timeout(timeout, reply_rx).await {
+      Ok(Ok(message)) => Ok(message),
+      Ok(Err(_)) => {
+        self.forget(&tag);
+        Err(eyre!("{destination:?} dropped the question"))
+      }
+      Err(_) => {
+        self.forget(&tag);
+        Err(eyre!("no reply from {destination:?} within {timeout:?}"))
+      }
+    }
+  }
+
+  /// Returns a snapshot of the dead-letter queue.
+  pub fn dead_letters(&self) -> DeadLetters {
+    self
+      .dead_letters
+      .lock()
+      .expect("dead letters are not poisoned")
+      .clone()
+  }
+
+  /// Creates a handle that isn't connected to a router, along with the
+  /// receiver its messages end up in.
+  #[cfg(test)]
+  pub fn detached() -> (RouterHandle, mpsc::UnboundedReceiver<Message>) {
+    let (tx, rx) = mpsc::unbounded_channel::<Message>();
+    let handle = RouterHandle {
+      tx,
+      channel_table: SharedChannelTable::default(),
+      pending: Pending::default(),
+      next_tag: Arc::new(AtomicU64::new(0)),
+      dead_letters: SharedDeadLetters::default(),
+    };
+    (handle, rx)
+  }
+
+  /// Stops waiting for a reply.
+  fn forget(&self, tag: &str) {
+    self
+      .pending
+      .lock()
+      .expect("pending asks are not poisoned")
+      .remove(tag);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use color_eyre::eyre::Result;
+  use pretty_assertions::assert_eq;
+
+  #[tokio::test]
+  async fn test_router_new() -> Result<()> {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let _ = Router::new(tx).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_register() -> Result<()> {
+    let (tx0, _) = mpsc::unbounded_channel::<Message>();
+    let (tx1, _) = mpsc::unbounded_channel::<Message>();
+    let (mut router, _) = Router::new(tx0).await?;
+    router.register(Address::Drop, tx1);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_ask() -> Result<()> {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let (mut router, router_tx) = Router::new(tx).await?;
+    let (responder_tx, mut responder_rx) = mpsc::unbounded_channel::<Message>();
+    router.register(Address::IrxClient, responder_tx);
+    router.run();
+    tokio::spawn(async move {
+      while let Some(message) = responder_rx.recv().await {
+        let reply = Message {
+          source: Address::IrxClient,
+          destination: message.source,
+          payload: Payload::String(String::from("pong")),
+          tag: message.tag,
+          ..Message::default()
+        };
+        router_tx.send(reply).ok();
+      }
+    });
+    let handle = router.handle();
+    let reply = handle
+      .ask(
+        Address::StateDisplay,
+        Address::IrxClient,
+        Payload::String(String::from("ping")),
+        Duration::from_secs(1),
+      )
+      .await?;
+    assert_eq!(reply.payload, Payload::String(String::from("pong")));
+    assert!(handle.pending.lock().unwrap().is_empty());
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_dead_letters() -> Result<()> {
+    let (app_tx, mut app_rx) = mpsc::unbounded_channel::<Message>();
+    let (mut router, router_tx) = Router::new(app_tx).await?;
+    let (closed_tx, closed_rx) = mpsc::unbounded_channel::<Message>();
+    drop(closed_rx);
+    router.register(Address::Home, closed_tx);
+    router.run();
+    for destination in [Address::Session, Address::Home] {
+      router_tx.send(Message {
+        destination,
+        ..Message::default()
+      })?;
+    }
+    let notice = app_rx.recv().await.expect("app is told");
+    let Payload::DeadLetter(letter) = notice.payload else {
+      panic!("unexpected payload {:?}", notice.payload);
+    };
+    assert_eq!(letter.message.destination, Address::Session);
+    assert_eq!(letter.reason, Undeliverable::Unregistered);
+    app_rx.recv().await.expect("app is told");
+    let dead_letters = router.handle().dead_letters();
+    assert_eq!(dead_letters.unregistered, 1);
+    assert_eq!(dead_letters.closed, 1);
+    assert_eq!(dead_letters.recent.len(), 2);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_ask_unregistered() -> Result<()> {
+    let (tx, _rx) = mpsc::unbounded_channel::<Message>();
+    let (mut router, _) = Router::new(tx).await?;
+    router.run();
+    let handle = router.handle();
+    let reply = handle
+      .ask(
+        Address::StateDisplay,
+        Address::IrxClient,
+        Payload::Empty,
+        Duration::from_secs(60),
+      )
+      .await;
+    assert!(reply.is_err());
+    assert!(handle.pending.lock().unwrap().is_empty());
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_ask_cached() -> Result<()> {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let (mut router, router_tx) = Router::new(tx).await?;
+    let (responder_tx, mut responder_rx) = mpsc::unbounded_channel::<Message>();
+    router.register(Address::IrxClient, responder_tx);
+    router.run();
+    let asked = Arc::new(AtomicU64::new(0));
+    let count = asked.clone();
+    tokio::spawn(async move {
+      while let Some(message) = responder_rx.recv().await {
+        if message.kind != Kind::Ask {
+          continue;
+        }
+        let n = count.fetch_add(1, Ordering::Relaxed);
+        let reply = Message {
+          source: Address::IrxClient,
+          destination: message.source,
+          payload: Payload::String(format!("answer {n}")),
+          tag: message.tag,
+          cacheable: Cacheable::Yes,
+          kind: Kind::Tell,
+        };
+        router_tx.send(reply).ok();
+      }
+    });
+    let handle = router.handle();
+    let ask = || {
+      handle.ask(
+        Address::StateDisplay,
+        Address::IrxClient,
+        Payload::Empty,
+        Duration::from_secs(1),
+      )
+    };
+    assert_eq!(ask().await?.payload, Payload::String("answer 0".into()));
+    assert_eq!(ask().await?.payload, Payload::String("answer 0".into()));
+    assert_eq!(asked.load(Ordering::Relaxed), 1);
+
+    handle.send(Message {
+      source: Address::IrxClient,
+      destination: Address::IrxClient,
+      cacheable: Cacheable::Yes,
+      ..Message::default()
+    })?;
+    assert_eq!(ask().await?.payload, Payload::String("answer 1".into()));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_register_while_running() -> Result<()> {
+    let (app_tx, mut app_rx) = mpsc::unbounded_channel::<Message>();
+    let (mut router, router_tx) = Router::new(app_tx).await?;
+    router.run();
+    let handle = router.handle();
+    let job = Address::Job(JobId(7));
+    let (job_tx, mut job_rx) = mpsc::unbounded_channel::<Message>();
+    handle.register(job.clone(), job_tx);
+    let message = Message {
+      destination: job.clone(),
+      payload: Payload::JobLog(JobId(7), String::from("hello")),
+      ..Message::default()
+    };
+    router_tx.send(message.clone())?;
+    assert_eq!(job_rx.recv().await, Some(message.clone()));
+
+    assert!(handle.unregister(&job).is_some());
+    router_tx.send(message)?;
+    let notice = app_rx.recv().await.expect("app is told");
+    assert!(matches!(notice.payload, Payload::DeadLetter(_)));
+    assert_eq!(handle.dead_letters().unregistered, 1);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_ask_timeout() -> Result<()> {
+    let (tx, _) = mpsc::unbounded_channel::<Message>();
+    let (mut router, _) = Router::new(tx).await?;
+    let (silent_tx, _silent_rx) = mpsc::unbounded_channel::<Message>();
+    router.register(Address::IrxClient, silent_tx);
+    router.run();
+    let handle = router.handle();
+    let reply = handle
+      .ask(
+        Address::StateDisplay,
+        Address::IrxClient,
+        Payload::Empty,
+        Duration::from_millis(10),
+      )
+      .await;
+    assert!(reply.is_err());
+    assert!(handle.pending.lock().unwrap().is_empty());
+    Ok(())
+  }
+}
@@ -1,12 +1,17 @@
 use color_eyre::eyre::Result;
 use directories::ProjectDirs;
 use lazy_static::lazy_static;
-use std::path::PathBuf;
+use std::{
+  fmt::{self, Write},
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+use tokio::sync::mpsc;
 use tracing::error;
 use tracing_error::ErrorLayer;
 use tracing_subscriber::{
-  self, prelude::__tracing_subscriber_SubscriberExt, util::SubscriberInitExt,
-  Layer,
+  self, layer::Context, prelude::__tracing_subscriber_SubscriberExt,
+  util::SubscriberInitExt, Layer,
 };
 
 // Static variable containing the current Git commit hash.
@@ -30,8 +35,14 @@ lazy_static! {
     pub static ref LOG_ENV: String = format!("{}_LOGLEVEL", PROJECT_NAME.clone());
     /// Filename for the log file.
     pub static ref LOG_FILE: String = format!("{}.log", env!("CARGO_PKG_NAME"));
+    /// Receiver for the records written to the log file, until it's taken.
+    static ref LOG_RECORDS: Mutex<Option<mpsc::UnboundedReceiver<LogRecord>>> =
+        Mutex::new(None);
 }
 
+/// A record written to the log file, as shown in the Logs pane.
+pub type LogRecord = (log::Level, String);
+
 /// Retrieves the project directory information.
 ///
 /// # Returns
@@ -132,6 +143,22 @@ pub fn get_config_dir() -> PathBuf {
   directory
 }
 
+/// Expands a leading `~` in a path to the user's home directory.
+///
+/// # Parameters
+///
+/// * `path`: The path to expand.
+///
+/// # Returns
+///
+/// `PathBuf` - The expanded path, or `path` unchanged if it has no `~`.
+pub fn expand_home(path: &Path) -> PathBuf {
+  match (path.strip_prefix("~"), dirs::home_dir()) {
+    (Ok(rest), Some(home)) => home.join(rest),
+    _ => path.to_path_buf(),
+  }
+}
+
 /// Initializes the logging system for the application.
 ///
 /// Configures and sets up file-based logging.
@@ -150,6 +177,10 @@ pub fn initialize_logging() -> Result<()> {
       .or_else(|_| std::env::var(LOG_ENV.clone()))
       .unwrap_or_else(|_| format!("{}=info", env!("CARGO_CRATE_NAME"))),
   );
+  let (tx, rx) = mpsc::unbounded_channel();
+  *LOG_RECORDS.lock().unwrap_or_else(|e| e.into_inner()) = Some(rx);
+  let tail_subscriber = TailLayer { tx }
+    .with_filter(tracing_subscriber::filter::EnvFilter::from_default_env());
   let file_subscriber = tracing_subscriber::fmt::layer()
     .with_file(true)
     .with_line_number(true)
@@ -159,11 +190,60 @@ pub fn initialize_logging() -> Result<()> {
     .with_filter(tracing_subscriber::filter::EnvFilter::from_default_env());
   tracing_subscriber::registry()
     .with(file_subscriber)
+    .with(tail_subscriber)
     .with(ErrorLayer::default())
     .init();
   Ok(())
 }
 
+/// Takes the receiver for the records written to the log file.
+///
+/// # Returns
+/// The receiver, or `None` if logging isn't initialized or the receiver was
+/// already taken.
+pub fn take_log_records() -> Option<mpsc::UnboundedReceiver<LogRecord>> {
+  LOG_RECORDS.lock().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// A `tracing` layer that copies events to a channel, for the Logs pane to
+/// tail.
+struct TailLayer {
+  tx: mpsc::UnboundedSender<LogRecord>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for TailLayer {
+  fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+    let level = match *event.metadata().level() {
+      tracing::Level::ERROR => log::Level::Error,
+      tracing::Level::WARN => log::Level::Warn,
+      tracing::Level::INFO => log::Level::Info,
+      tracing::Level::DEBUG => log::Level::Debug,
+      tracing::Level::TRACE => log::Level::Trace,
+    };
+    let mut message = String::new();
+    event.record(&mut MessageVisitor(&mut message));
+    self.tx.send((level, message)).ok();
+  }
+}
+
+/// Formats an event's message, followed by its other fields.
+struct MessageVisitor<'a>(&'a mut String);
+
+impl tracing::field::Visit for MessageVisitor<'_> {
+  fn record_debug(
+    &mut self,
+    field: &tracing::field::Field,
+    value: &dyn fmt::Debug,
+  ) {
+    match field.name() {
+      "message" => write!(self.0, "{value:?}").ok(),
+      // Where events forwarded from `log` came from
+      name if name.starts_with("log.") => None,
+      name => write!(self.0, " {name}={value:?}").ok(),
+    };
+  }
+}
+
 /// Macro for generating `tracing` events.
 ///
 /// Similar to `std::dbg!`, but for `tracing`. By default, the verbosity level is `DEBUG`.
@@ -243,6 +323,13 @@ mod tests {
     get_config_dir();
   }
 
+  #[test]
+  fn test_expand_home() {
+    let home = dirs::home_dir().unwrap();
+    assert_eq!(expand_home(Path::new("~/data")), home.join("data"));
+    assert_eq!(expand_home(Path::new("/data")), PathBuf::from("/data"));
+  }
+
   #[test]
   fn test_version() {
     version();